@@ -0,0 +1,233 @@
+//! Step by step explanation of how the headline numbers are derived from the scenario.
+
+use crate::model::{AdoptionRamp, BreakEven, ConfTimeUnit, Failure, FrequencyGrowth, InducedDemand, Scenario, TimeUnit};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub label: String,
+    pub formula: String,
+}
+
+impl Step {
+    fn new(label: &str, formula: String) -> Self {
+        Self { label: label.to_string(), formula }
+    }
+}
+
+pub fn breakdown(scenario: &Scenario) -> Vec<Step> {
+    let conf = &scenario.conf_time_unit;
+    let hours_per_day = conf.number_of_hours_per_day();
+    let executions_per_day = scenario.executions_per_day();
+    let before_execution = scenario.before_execution_hours();
+    let after_execution = scenario.after_execution_hours();
+    let adopted_execution = scenario.adopted_execution_hours(scenario.adoption_percent / 100.0, before_execution);
+    let invest_hours = scenario.invest_time_in_hours();
+    let invest_days = scenario.invest_days();
+    let before_daily = scenario.before_per_day_in_hours();
+    let maintenance_daily = scenario.maintenance_per_day_in_hours();
+    let after_daily = scenario.after_per_day_in_hours();
+    let saving_days = scenario.alive_days(scenario.scale_number_of_day as f64);
+    // nothing is saved while waiting for a delayed start
+    let saved_days = (saving_days - scenario.start_delay_days).max(0.0);
+    let saved_hours = scenario.saved_hours_at(scenario.scale_number_of_day as f64);
+    // with constant daily rates both lines are straight and the break-even has a closed form
+    let linear = scenario.has_constant_rates();
+    let effective = if scenario.multitasking.is_some() { " (effective)" } else { "" };
+
+    let mut steps = vec![
+        Step::new("Executions per day", format!("{} per {} × {} {} per day = {}",
+                                                number(scenario.executions_per_unit()), scenario.repeat_count_time_unit.singular(conf),
+                                                number(scenario.repeat_count_time_unit.to_times_per_days(1.0, conf)), scenario.repeat_count_time_unit.plural(conf),
+                                                number(executions_per_day))),
+        Step::new("Hours per execution before", format!("{} {}{}{} = {} h", scenario.before_taken_time, scenario.before_taken_time_unit.plural(conf),
+                                                         effective, failure_term(&scenario.before_failure, conf) + &refocus_term(scenario, false), number(before_execution))),
+        Step::new("Hours per execution after", format!("{} {}{}{} = {} h", scenario.after_taken_time, scenario.after_taken_time_unit.plural(conf),
+                                                        effective, failure_term(&scenario.after_failure, conf) + &refocus_term(scenario, true), number(after_execution))),
+        Step::new("Daily cost before", format!("min(24, {} h × {}) × {} people = {} h/day", number(before_execution), number(executions_per_day), scenario.people_count, number(before_daily))),
+    ];
+    if maintenance_daily > 0.0 {
+        steps.push(Step::new("Maintenance per day", format!("{} {} per {} = {} h/day", scenario.maintenance_time, scenario.maintenance_time_unit.plural(conf),
+                                                             scenario.maintenance_period_unit.singular(conf), number(maintenance_daily))));
+        steps.push(Step::new("Daily cost after", format!("min(24, {} h × {}) × {} people + {} h maintenance = {} h/day",
+                                                         number(adopted_execution), number(executions_per_day), scenario.people_count, number(maintenance_daily), number(after_daily))));
+    } else {
+        steps.push(Step::new("Daily cost after", format!("min(24, {} h × {}) × {} people = {} h/day", number(adopted_execution), number(executions_per_day), scenario.people_count, number(after_daily))));
+    }
+    steps.extend([
+        Step::new("Investment", format!("{} {} × {} investors = {} h", scenario.invest_taken_time, scenario.invest_taken_time_unit.plural(conf), scenario.investors_count(), number(invest_hours))),
+        Step::new("Investment in working days", format!("{} h / {} investors / {} h per day = {} days",
+                                                                number(invest_hours), scenario.investors_count(), number(scenario.invest_hours_per_day()), number(invest_days))),
+    ]);
+    if let Some(hours_per_week) = scenario.invest_hours_per_week {
+        steps.push(Step::new("Part-time investment", format!("min({} h, {} h per week / {} days) = {} h per day, paying {} h of the current process meanwhile",
+                                                              number(hours_per_day), number(hours_per_week), number(conf.calendar.number_of_day_per_week()),
+                                                              number(scenario.invest_hours_per_day()),
+                                                              number(scenario.before_at(scenario.ship_day()) - scenario.before_at(scenario.start_delay_days)))));
+    }
+    match &scenario.frequency_growth {
+        FrequencyGrowth::Constant => {}
+        FrequencyGrowth::PercentPerMonth(percent) => steps.push(Step::new("Frequency growth", format!("{:+}% per month ⇒ × {} by day {}",
+                                                                                                       number(*percent), number(scenario.frequency_growth.factor(saving_days, conf)), saving_days))),
+        FrequencyGrowth::Schedule(growth_steps) => steps.push(Step::new("Frequency growth", growth_steps.iter()
+            .map(|step| format!("× {} from day {}", number(step.multiplier), number(step.from_day))).collect::<Vec<String>>().join(", "))),
+    }
+    if scenario.before_degradation_percent_per_month != 0.0 {
+        steps.push(Step::new("Degradation before", format!("{:+}% per month ⇒ {} h per execution by day {}", number(scenario.before_degradation_percent_per_month),
+                                                            number(scenario.before_execution_hours_at(saving_days)), saving_days)));
+    }
+    if scenario.induced_demand != InducedDemand::Unchanged {
+        let scale = scenario.scale_number_of_day as f64;
+        steps.push(Step::new("Induced demand", format!("× {} executions once optimized ⇒ {} more executions by day {}",
+                                                        number(scenario.induced_demand_factor()), number(scenario.extra_executions_at(scale)), scenario.scale_number_of_day)));
+    }
+    if let Some(multitasking) = &scenario.multitasking {
+        let step = |time: f64, unit: &TimeUnit, waiting_percent: f64| {
+            let hours = unit.to_hours(time, conf);
+            format!("{} h with {}% waiting, {}% of it put to good use, {} {} to switch ⇒ {} h", number(hours), number(waiting_percent),
+                    number(multitasking.efficiency_percent), number(multitasking.context_switch_time), multitasking.context_switch_time_unit.plural(conf),
+                    number(multitasking.effective_hours(hours, waiting_percent, conf)))
+        };
+        steps.push(Step::new("Effective time before", step(scenario.before_taken_time, &scenario.before_taken_time_unit, multitasking.before_waiting_percent)));
+        steps.push(Step::new("Effective time after", step(scenario.after_taken_time, &scenario.after_taken_time_unit, multitasking.after_waiting_percent)));
+    }
+    if scenario.adoption_percent < 100.0 || scenario.adoption_ramp != AdoptionRamp::Immediate {
+        let ramp = match scenario.adoption_ramp {
+            AdoptionRamp::Immediate => "immediately".to_string(),
+            AdoptionRamp::Linear { days } => format!("linearly over {} days", number(days)),
+            AdoptionRamp::Exponential { days } => format!("exponentially over {} days", number(days)),
+        };
+        steps.push(Step::new("Adoption", format!("{}% of the executions, reached {}, the others cost {} h as before ⇒ {} h per execution",
+                                                  number(scenario.adoption_percent), ramp, number(before_execution), number(adopted_execution))));
+    }
+    let ship_day = scenario.ship_day();
+    let running_share = scenario.running_share_during_investment();
+    // the current process keeps running until the investment starts, or until it ships when investing part-time
+    let paused_from = if running_share >= 1.0 { ship_day } else { scenario.start_delay_days };
+    let spent_at_ship_day = scenario.improved_at(ship_day);
+    if running_share > 0.0 && running_share < 1.0 {
+        steps.push(Step::new("Team during the investment", format!("{} of {} people keep running the current process, paying {} h of it meanwhile",
+                                                                    number(scenario.people_count as f64 - scenario.investors_count()), scenario.people_count,
+                                                                    number(running_share * (scenario.before_at(ship_day) - scenario.before_at(scenario.start_delay_days))))));
+    }
+    if scenario.start_delay_days > 0.0 {
+        steps.push(Step::new("Delayed start", format!("investing from day {} to day {}, {} h more than starting now by day {}",
+                                                       number(scenario.start_delay_days), number(ship_day),
+                                                       number(scenario.waiting_cost_at(scenario.scale_number_of_day as f64)), scenario.scale_number_of_day)));
+    }
+    let break_even = match scenario.intersection() {
+        BreakEven::PaysOffOnDay { day, .. } | BreakEven::NeverWithinHorizon { day, .. } if !linear => format!(
+            "Σ before = {} + Σ after since day {}, solved day by day ⇒ d = {} days", number(spent_at_ship_day), number(ship_day), number(day)),
+        BreakEven::PaysOffOnDay { day, .. } | BreakEven::NeverWithinHorizon { day, .. } if running_share > 0.0 && running_share < 1.0 => format!(
+            "{b}·d = {c} + {a}·(d - {sd}) ⇒ d = ({c} - {a} × {sd}) / ({b} - {a}) = {d} days",
+            b = number(before_daily), a = number(after_daily), c = number(spent_at_ship_day), sd = number(ship_day), d = number(day)),
+        BreakEven::PaysOffOnDay { day, .. } | BreakEven::NeverWithinHorizon { day, .. } if paused_from > 0.0 => format!(
+            "{b}·d = {b}·{p} + {i} + {a}·(d - {sd}) ⇒ d = ({b} × {p} + {i} - {a} × {sd}) / ({b} - {a}) = {d} days",
+            b = number(before_daily), a = number(after_daily), p = number(paused_from), i = number(invest_hours), sd = number(ship_day), d = number(day)),
+        BreakEven::PaysOffOnDay { day, .. } | BreakEven::NeverWithinHorizon { day, .. } => format!(
+            "{b}·d = {i} + {a}·(d - {id}) ⇒ d = ({i} - {a} × {id}) / ({b} - {a}) = {d} days",
+            b = number(before_daily), a = number(after_daily), i = number(invest_hours), id = number(invest_days), d = number(day)),
+        BreakEven::PaysOffDuringInvestment { day, .. } => format!("{} h spent in {} days ≥ {} h ⇒ pays off by the end of the investment, day {}",
+                                                                  number(scenario.before_at(ship_day)), number(ship_day), number(spent_at_ship_day), number(day)),
+        BreakEven::NeverAfterNotFaster => format!("{} h/day ≥ {} h/day ⇒ never pays off", number(after_daily), number(before_daily)),
+        BreakEven::CapacitySaturated => "daily cost before and after are both clamped to 24 h ⇒ never pays off".to_string(),
+        BreakEven::NeverBeforeSunset { day, lifetime_days } => format!("recovered on day {} at the earliest, the process stops existing on day {} ⇒ never pays off",
+                                                                        number(day), number(lifetime_days)),
+        BreakEven::NeverRecovered => "the daily saving fades out with the frequency before recovering the investment ⇒ never pays off".to_string(),
+    };
+    steps.push(Step::new("Break-even", break_even));
+    if linear {
+        steps.push(Step::new("Saved time", format!("({} - {}) h/day × {} days = {} h",
+                                                   number(before_daily), number(after_daily), number(saved_days), number(saved_hours))));
+    } else {
+        steps.push(Step::new("Saved time", format!("Σ (before - after) over {} days = {} h", number(saved_days), number(saved_hours))));
+    }
+    if let Some(risk_adjusted) = scenario.risk_adjusted() {
+        let break_even = risk_adjusted.intersection().point().map_or("never".to_string(), |(day, _)| format!("day {}", number(day)));
+        steps.push(Step::new("Risk-adjusted", format!("{}% success, investment × {} ⇒ {} h invested, {} h/day after ⇒ break-even {}",
+                                                       number(scenario.success_probability_percent), number(scenario.invest_overrun_factor),
+                                                       number(risk_adjusted.invest_time_in_hours()), number(risk_adjusted.after_per_day_in_hours()), break_even)));
+    }
+    if let Some(percent) = scenario.discount_rate_percent_per_year {
+        let scale = scenario.scale_number_of_day as f64;
+        steps.push(Step::new("Discounted net saving", format!("Σ (before - improved) × (1 + {}%)^(-d / {} days) over {} days = {} h",
+                                                               number(percent), number(conf.number_of_day_per_year()), scenario.scale_number_of_day,
+                                                               number(scenario.discounted_net_saved_hours_at(scale)))));
+    }
+    let cost_model = &scenario.cost_model;
+    if cost_model.enabled() {
+        let hourly_cost = cost_model.cost(1.0);
+        steps.push(Step::new("Cost of 1 hour", format!("{} × {} = {}", cost_model.format(cost_model.hourly_rate), number(cost_model.overhead_multiplier), cost_model.format(hourly_cost))));
+        steps.push(Step::new("Investment cost", format!("{} h × {} = {}", number(invest_hours), cost_model.format(hourly_cost), cost_model.format(cost_model.cost(invest_hours)))));
+        steps.push(Step::new("Daily money cost before", format!("{} h × {} = {}", number(before_daily), cost_model.format(hourly_cost), cost_model.format(cost_model.cost(before_daily)))));
+        steps.push(Step::new("Daily money cost after", format!("{} h × {} = {}", number(after_daily), cost_model.format(hourly_cost), cost_model.format(cost_model.cost(after_daily)))));
+        steps.push(Step::new("Saved money", format!("{} h × {} = {}", number(saved_hours), cost_model.format(hourly_cost), cost_model.format(cost_model.cost(saved_hours)))));
+    }
+    steps
+}
+
+pub fn to_plain_text(steps: &[Step]) -> String {
+    steps.iter().map(|step| format!("{}: {}", step.label, step.formula)).collect::<Vec<String>>().join("\n")
+}
+
+/// " + 1% × 2 hours of recovery", empty without failures.
+fn failure_term(failure: &Failure, conf: &ConfTimeUnit) -> String {
+    if failure.rate_percent == 0.0 {
+        return String::new();
+    }
+    format!(" + {}% × {} {} of recovery", number(failure.rate_percent), number(failure.recovery_time), failure.recovery_time_unit.plural(conf))
+}
+
+/// " + 15 minutes to refocus", empty when the process does not interrupt anything.
+fn refocus_term(scenario: &Scenario, after: bool) -> String {
+    match &scenario.refocus {
+        Some(refocus) if !after || refocus.after => format!(" + {} {} to refocus", number(refocus.time), refocus.time_unit.plural(&scenario.conf_time_unit)),
+        _ => String::new(),
+    }
+}
+
+/// Up to 4 decimals, without trailing zeros.
+fn number(value: f64) -> String {
+    let formatted = format!("{:.4}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::breakdown::{breakdown, to_plain_text};
+    use crate::model::{AdoptionRamp, Scenario};
+
+    #[test]
+    fn default_scenario_breakdown() {
+        let text = to_plain_text(&breakdown(&Scenario::default()));
+        assert!(text.contains("Executions per day: 20 per hour × 8 hours per day = 160"));
+        assert!(text.contains("Investment in working days: 2 h / 1 investors / 8 h per day = 0.25 days"));
+        assert!(text.contains("= 1.4167 days"));
+        assert!(text.contains("Saved time: (1.7778 - 0.4444) h/day × 90 days = 120 h"));
+    }
+
+    #[test]
+    fn delayed_start_breakdown() {
+        let text = to_plain_text(&breakdown(&Scenario { start_delay_days: 10.0, ..Scenario::default() }));
+        assert!(text.contains("Delayed start: investing from day 10 to day 10.25, 13.3333 h more than starting now by day 90"));
+        assert!(text.contains("= 11.4167 days"));
+        let part_time = to_plain_text(&breakdown(&Scenario { start_delay_days: 10.0, invest_hours_per_week: Some(4.0), ..Scenario::default() }));
+        // 2.5 days of the current process from day 10
+        assert!(part_time.contains("paying 4.4444 h of the current process meanwhile"));
+    }
+
+    #[test]
+    fn ramp_and_lifetime_breakdown() {
+        // the daily saving grows during the ramp, the closed form does not apply
+        let scenario = Scenario { adoption_ramp: AdoptionRamp::Linear { days: 20.0 }, lifetime_days: Some(60.0), ..Scenario::default() };
+        let text = to_plain_text(&breakdown(&scenario));
+        assert!(text.contains("solved day by day ⇒ d = 7.0813 days"));
+        assert!(text.contains("Saved time: Σ (before - after) over 60 days = 67.8281 h"));
+    }
+
+    #[test]
+    fn team_breakdown() {
+        let text = to_plain_text(&breakdown(&Scenario { people_count: 12, ..Scenario::default() }));
+        assert!(text.contains("Team during the investment: 11 of 12 people keep running the current process, paying 4.8889 h of it meanwhile"));
+        assert!(text.contains("21.3333·d = 6.8889 + 5.3333·(d - 0.25) ⇒ d = (6.8889 - 5.3333 × 0.25) / (21.3333 - 5.3333) = 0.3472 days"));
+    }
+}
@@ -0,0 +1,314 @@
+//! Working calendar: which days are worked, for how many hours, and how many days are off during a year.
+
+pub const DAYS_PER_YEAR: f64 = 365.25;
+pub const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+pub const MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    pub fn to_days(self) -> i64 {
+        let year = if self.month <= 2 { self.year - 1 } else { self.year } as i64;
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Inverse of [`Date::to_days`].
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = if days >= 0 { days } else { days - 146096 } / 146097;
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+        let year = (year_of_era + era * 400) as i32 + if month <= 2 { 1 } else { 0 };
+        Self { year, month, day }
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// "14 March 2026"
+    pub fn format_long(&self) -> String {
+        format!("{} {} {}", self.day, MONTHS[self.month as usize - 1], self.year)
+    }
+
+    /// "14 Mar"
+    pub fn format_short(&self) -> String {
+        format!("{} {}", self.day, &MONTHS[self.month as usize - 1][..3])
+    }
+
+    /// 0 for Monday to 6 for Sunday.
+    pub fn weekday(&self) -> usize {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as usize
+    }
+}
+
+/// A public holiday, on the same date every year.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Holiday {
+    pub name: String,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Holiday {
+    fn new(name: &str, month: u32, day: u32) -> Self {
+        Self { name: name.to_string(), month, day }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Calendar {
+    /// Worked hours for each day of the week, from Monday to Sunday, 0 for a day off.
+    pub hours_per_weekday: [f64; 7],
+    pub public_holidays: Vec<Holiday>,
+    pub vacation_days_per_year: f64,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self::full_time(8.0, 5)
+    }
+}
+
+impl Calendar {
+    /// `days_per_week` days of `hours_per_day` hours from Monday, no day off during the year.
+    pub fn full_time(hours_per_day: f64, days_per_week: usize) -> Self {
+        let mut hours_per_weekday = [0.0; 7];
+        hours_per_weekday.iter_mut().take(days_per_week).for_each(|hours| *hours = hours_per_day);
+        Self { hours_per_weekday, public_holidays: vec![], vacation_days_per_year: 0.0 }
+    }
+
+    pub fn number_of_day_per_week(&self) -> f64 {
+        self.hours_per_weekday.iter().filter(|hours| **hours > 0.0).count() as f64
+    }
+
+    /// Average over worked days.
+    pub fn number_of_hours_per_day(&self) -> f64 {
+        let days = self.number_of_day_per_week();
+        if days == 0.0 {
+            return 0.0;
+        }
+        self.hours_per_weekday.iter().sum::<f64>() / days
+    }
+
+    /// Worked days in a year, once public holidays falling on a worked day and vacations are removed.
+    pub fn number_of_day_per_year(&self) -> f64 {
+        let days_per_week = self.number_of_day_per_week();
+        let holidays_on_worked_days = self.public_holidays.len() as f64 * days_per_week / 7.0;
+        (DAYS_PER_YEAR / 7.0 * days_per_week - holidays_on_worked_days - self.vacation_days_per_year).max(0.0)
+    }
+
+    pub fn number_of_day_per_month(&self) -> f64 {
+        self.number_of_day_per_year() / 12.0
+    }
+
+    /// Vacations have no date, so only the weekly schedule and public holidays are considered.
+    pub fn is_working_day(&self, date: &Date) -> bool {
+        self.hours_per_weekday[date.weekday()] > 0.0
+            && !self.public_holidays.iter().any(|holiday| holiday.month == date.month && holiday.day == date.day)
+    }
+
+    /// Date of the working day `day` of the projection, day 0 being the first working day from `start`.
+    /// Whole weeks are skipped at once, and the walk gives up after a year without any working day, e.g. when every
+    /// worked day is a public holiday.
+    pub fn working_day_date(&self, start: Date, day: f64) -> Date {
+        let mut date = start;
+        let mut remaining = day.max(0.0).floor() as i64;
+        let days_per_week = self.number_of_day_per_week() as i64;
+        let mut idle_days = 0;
+        while days_per_week > 0 && remaining >= days_per_week && idle_days <= DAYS_PER_YEAR as i64 {
+            let weeks = remaining / days_per_week;
+            let end = date.add_days(weeks * 7);
+            let working_days = (weeks * days_per_week - self.worked_holidays_between(date, end)).max(0);
+            idle_days = if working_days == 0 { idle_days + weeks * 7 } else { 0 };
+            remaining -= working_days;
+            date = end;
+        }
+        while idle_days <= DAYS_PER_YEAR as i64 {
+            if self.is_working_day(&date) {
+                if remaining == 0 {
+                    return date;
+                }
+                remaining -= 1;
+                idle_days = 0;
+            } else {
+                idle_days += 1;
+            }
+            date = date.add_days(1);
+        }
+        date
+    }
+
+    /// Public holidays falling on a worked day from `from` included to `to` excluded.
+    fn worked_holidays_between(&self, from: Date, to: Date) -> i64 {
+        let (from_days, to_days) = (from.to_days(), to.to_days());
+        let mut dates: Vec<i64> = (from.year..=to.year)
+            .flat_map(|year| self.public_holidays.iter().map(move |holiday| Date::new(year, holiday.month, holiday.day)))
+            // a 29 February in a common year does not exist
+            .filter(|date| Date::from_days(date.to_days()) == *date && self.hours_per_weekday[date.weekday()] > 0.0)
+            .map(Date::to_days)
+            .filter(|days| (from_days..to_days).contains(days))
+            .collect();
+        dates.sort_unstable();
+        dates.dedup();
+        dates.len() as i64
+    }
+}
+
+/// Common calendars, only public holidays on a fixed date are listed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarPreset {
+    France,
+    Germany,
+    UnitedKingdom,
+    UnitedStates,
+}
+
+impl CalendarPreset {
+    pub const ALL: [CalendarPreset; 4] = [CalendarPreset::France, CalendarPreset::Germany, CalendarPreset::UnitedKingdom, CalendarPreset::UnitedStates];
+
+    pub fn name(&self) -> &str {
+        match self {
+            CalendarPreset::France => "France",
+            CalendarPreset::Germany => "Germany",
+            CalendarPreset::UnitedKingdom => "United Kingdom",
+            CalendarPreset::UnitedStates => "United States",
+        }
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        match self {
+            CalendarPreset::France => Calendar {
+                public_holidays: vec![
+                    Holiday::new("New Year's Day", 1, 1),
+                    Holiday::new("Labour Day", 5, 1),
+                    Holiday::new("Victory in Europe Day", 5, 8),
+                    Holiday::new("Bastille Day", 7, 14),
+                    Holiday::new("Assumption Day", 8, 15),
+                    Holiday::new("All Saints' Day", 11, 1),
+                    Holiday::new("Armistice Day", 11, 11),
+                    Holiday::new("Christmas Day", 12, 25),
+                ],
+                vacation_days_per_year: 25.0,
+                ..Calendar::full_time(7.0, 5)
+            },
+            CalendarPreset::Germany => Calendar {
+                public_holidays: vec![
+                    Holiday::new("New Year's Day", 1, 1),
+                    Holiday::new("Labour Day", 5, 1),
+                    Holiday::new("German Unity Day", 10, 3),
+                    Holiday::new("Christmas Day", 12, 25),
+                    Holiday::new("Boxing Day", 12, 26),
+                ],
+                vacation_days_per_year: 28.0,
+                ..Calendar::full_time(8.0, 5)
+            },
+            CalendarPreset::UnitedKingdom => Calendar {
+                public_holidays: vec![
+                    Holiday::new("New Year's Day", 1, 1),
+                    Holiday::new("Christmas Day", 12, 25),
+                    Holiday::new("Boxing Day", 12, 26),
+                ],
+                vacation_days_per_year: 20.0,
+                ..Calendar::full_time(7.5, 5)
+            },
+            CalendarPreset::UnitedStates => Calendar {
+                public_holidays: vec![
+                    Holiday::new("New Year's Day", 1, 1),
+                    Holiday::new("Juneteenth", 6, 19),
+                    Holiday::new("Independence Day", 7, 4),
+                    Holiday::new("Veterans Day", 11, 11),
+                    Holiday::new("Christmas Day", 12, 25),
+                ],
+                vacation_days_per_year: 10.0,
+                ..Calendar::full_time(8.0, 5)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calendar::{Calendar, CalendarPreset, Date, Holiday, DAYS_PER_YEAR};
+
+    #[test]
+    fn dates() {
+        assert_eq!(Date::new(1970, 1, 1).to_days(), 0);
+        assert_eq!(Date::new(2024, 3, 1).add_days(-1), Date::new(2024, 2, 29));
+        assert_eq!(Date::from_days(Date::new(2026, 10, 17).to_days()), Date::new(2026, 10, 17));
+        // 2026-10-17 is a Saturday
+        assert_eq!(Date::new(2026, 10, 17).weekday(), 5);
+    }
+
+    #[test]
+    fn working_days() {
+        let calendar = CalendarPreset::France.calendar();
+        // Friday 10 July 2026, Bastille Day is on Tuesday 14
+        let start = Date::new(2026, 7, 10);
+        assert_eq!(calendar.working_day_date(start, 0.0), start);
+        assert_eq!(calendar.working_day_date(start, 1.5), Date::new(2026, 7, 13));
+        assert_eq!(calendar.working_day_date(start, 2.0), Date::new(2026, 7, 15));
+        // skipping whole weeks lands on the same day as walking day by day
+        let walk = |day: usize| {
+            let mut date = start;
+            let mut remaining = day;
+            loop {
+                if calendar.is_working_day(&date) {
+                    if remaining == 0 {
+                        return date;
+                    }
+                    remaining -= 1;
+                }
+                date = date.add_days(1);
+            }
+        };
+        for day in [5, 7, 38, 251, 1000] {
+            assert_eq!(calendar.working_day_date(start, day as f64), walk(day));
+        }
+        // every worked day is a holiday: the walk gives up
+        let mut monday_only = Calendar::full_time(8.0, 1);
+        monday_only.public_holidays = (0..366).map(|day| Date::new(2024, 1, 1).add_days(day))
+            .map(|date| Holiday { name: String::new(), month: date.month, day: date.day }).collect();
+        assert_eq!(monday_only.working_day_date(start, 10.0).year, 2027);
+        assert_eq!(Date::new(2026, 3, 14).format_long(), "14 March 2026");
+        assert_eq!(Date::new(2026, 3, 14).format_short(), "14 Mar");
+    }
+
+    #[test]
+    fn part_time() {
+        let mut calendar = Calendar::full_time(8.0, 5);
+        calendar.hours_per_weekday[4] = 4.0;
+        assert_eq!(calendar.number_of_day_per_week(), 5.0);
+        assert_eq!(calendar.number_of_hours_per_day(), 7.2);
+        assert_eq!(calendar.number_of_day_per_year(), DAYS_PER_YEAR / 7.0 * 5.0);
+    }
+
+    #[test]
+    fn days_off() {
+        let calendar = CalendarPreset::France.calendar();
+        assert!((calendar.number_of_day_per_year() - (DAYS_PER_YEAR / 7.0 * 5.0 - 8.0 * 5.0 / 7.0 - 25.0)).abs() < 1e-9);
+        assert!(!calendar.is_working_day(&Date::new(2026, 7, 14)));
+        assert!(!calendar.is_working_day(&Date::new(2026, 10, 17)));
+        assert!(calendar.is_working_day(&Date::new(2026, 10, 16)));
+    }
+}
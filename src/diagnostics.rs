@@ -0,0 +1,420 @@
+//! Explains why a scenario does not pay off and which minimal change to the inputs would make it pay off.
+
+use crate::model::{value_to_human_duration, BreakEven, Failure, InducedDemand, RepeatMode, Scenario, TimeUnit};
+
+const BISECTION_ITERATIONS: usize = 60;
+const MIN_REPEAT_COUNT: f64 = 0.01;
+const MAX_REPEAT_COUNT: f64 = 10000.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Diagnostic {
+    /// The action is never repeated, there is nothing to save.
+    RepeatCountZero { min_repeat_count: Option<f64> },
+    /// Nobody adopts the optimised process, every execution goes through the current one.
+    NoAdoption { min_adoption_percent: Option<f64> },
+    /// The optimised process takes as long or longer than the current one.
+    AfterNotFaster { max_after_taken_time: Option<f64> },
+    /// The optimised process is faster, but fails often enough to cost as much as the current one.
+    FailuresEatSaving { max_after_failure_rate: Option<f64> },
+    /// Both processes are clamped to 24 hours a day: the optimisation does not bring the daily demand below it.
+    CapacitySaturated { max_after_taken_time: Option<f64> },
+    /// The optimised process is faster, but its maintenance eats all of the daily saving.
+    MaintenanceExceedsSaving { max_maintenance_time: Option<f64> },
+    /// The optimised process is faster, but repeated so much more often that it takes as much time as the current one.
+    /// The largest multiplier or target frequency still paying off, in the form of [`Scenario::induced_demand`].
+    InducedDemandEatsSaving { max_induced_demand: Option<f64> },
+    /// The frequency shrinks so fast that the saving fades out before the investment is recovered.
+    FrequencyShrinks,
+    /// The investment, once started, ships after the projection ends.
+    InvestmentExceedsHorizon { invest_days: f64, ship_day: f64, max_invest_taken_time: Option<f64> },
+    /// The process stops existing before the investment is recovered.
+    SunsetBeforeBreakEven { day: f64, lifetime_days: f64 },
+    /// The investment is recovered, only after the projection ends.
+    BreakEvenAfterHorizon { day: f64 },
+}
+
+impl Diagnostic {
+    pub fn cause(&self, scenario: &Scenario) -> String {
+        match self {
+            Diagnostic::RepeatCountZero { .. } => "You never repeat this action.".to_string(),
+            Diagnostic::NoAdoption { .. } => "Nobody adopts the optimised process, every execution still goes through the current one.".to_string(),
+            Diagnostic::AfterNotFaster { .. } if !included_costs(scenario).is_empty() => format!(
+                "Counting {}, an execution of the optimised process costs {} on average, which is not lower than the {} of the current one.",
+                included_costs(scenario).join(" and "), per_execution(scenario.after_execution_hours(), scenario),
+                per_execution(scenario.before_execution_hours(), scenario)),
+            Diagnostic::AfterNotFaster { .. } => format!("The optimised process takes {} {}, which is not lower than the current {} {}.",
+                                                         scenario.after_taken_time, scenario.after_taken_time_unit.plural(&scenario.conf_time_unit),
+                                                         scenario.before_taken_time, scenario.before_taken_time_unit.plural(&scenario.conf_time_unit)),
+            Diagnostic::FailuresEatSaving { .. } => format!("The optimised process fails {}% of the time, recovering makes an execution cost {} on average, not lower than the {} of the current one.",
+                                                            scenario.after_failure.rate_percent, per_execution(scenario.after_execution_hours(), scenario),
+                                                            per_execution(scenario.before_execution_hours(), scenario)),
+            Diagnostic::CapacitySaturated { .. } => "Repeated this often, both the current and the optimised process would take the whole day (24 hours).".to_string(),
+            Diagnostic::MaintenanceExceedsSaving { .. } => format!("Maintaining the optimised process takes {} {} per {}, more than the time it saves.",
+                                                                   scenario.maintenance_time, scenario.maintenance_time_unit.plural(&scenario.conf_time_unit),
+                                                                   scenario.maintenance_period_unit.singular(&scenario.conf_time_unit)),
+            Diagnostic::InducedDemandEatsSaving { .. } => format!("Repeated ×{:.2} as often once optimised, the extra executions eat the saving.",
+                                                                  scenario.induced_demand_factor()),
+            Diagnostic::FrequencyShrinks => "The action is repeated less and less often, the saving fades out before the investment is recovered.".to_string(),
+            Diagnostic::InvestmentExceedsHorizon { invest_days, .. } if invest_days.is_infinite() => "Not a single hour can be put into the investment, it never ends.".to_string(),
+            Diagnostic::InvestmentExceedsHorizon { invest_days, ship_day, .. } if scenario.start_delay_days > 0.0 => format!(
+                "Starting on day {}, the {:.1} days investment only ships on day {:.1}, after the {} days projection.",
+                scenario.start_delay_days, invest_days, ship_day, scenario.scale_number_of_day),
+            Diagnostic::InvestmentExceedsHorizon { invest_days, .. } => format!("The investment alone takes {:.1} days, longer than the {} days projection.",
+                                                                                invest_days, scenario.scale_number_of_day),
+            Diagnostic::SunsetBeforeBreakEven { day, lifetime_days } => format!("The investment would only be recovered after {:.1} days, the process stops existing after {} days.",
+                                                                                day, lifetime_days),
+            Diagnostic::BreakEvenAfterHorizon { day } => format!("The investment is only recovered after {:.1} days, the projection stops after {} days.",
+                                                                 day, scenario.scale_number_of_day),
+        }
+    }
+
+    pub fn suggestion(&self, scenario: &Scenario) -> Option<String> {
+        match self {
+            Diagnostic::RepeatCountZero { min_repeat_count } => min_repeat_count
+                .map(|count| format!("Repeat this action at least {} times per {}.", round_up(count), scenario.repeat_count_time_unit.singular(&scenario.conf_time_unit))),
+            Diagnostic::NoAdoption { min_adoption_percent } => min_adoption_percent
+                .map(|percent| format!("Get at least {}% of the executions to go through the optimised process.", round_up(percent))),
+            Diagnostic::AfterNotFaster { max_after_taken_time } | Diagnostic::CapacitySaturated { max_after_taken_time } => max_after_taken_time
+                .map(|time| format!("Reduce the optimised time to at most {} {}.", round_down(time), scenario.after_taken_time_unit.plural(&scenario.conf_time_unit))),
+            Diagnostic::FailuresEatSaving { max_after_failure_rate } => max_after_failure_rate
+                .map(|rate| format!("Make the optimised process fail at most {}% of the time.", round_down(rate))),
+            Diagnostic::MaintenanceExceedsSaving { max_maintenance_time } => max_maintenance_time
+                .map(|time| format!("Reduce the maintenance to at most {} {} per {}.", round_down(time), scenario.maintenance_time_unit.plural(&scenario.conf_time_unit),
+                                    scenario.maintenance_period_unit.singular(&scenario.conf_time_unit))),
+            Diagnostic::InducedDemandEatsSaving { max_induced_demand } => max_induced_demand.map(|value| match scenario.induced_demand {
+                InducedDemand::Target(_) => format!("Repeat the optimised process at most {} times per {}.", round_down(value),
+                                                    scenario.repeat_count_time_unit.singular(&scenario.conf_time_unit)),
+                _ => format!("Repeat the optimised process at most ×{} as often.", round_down(value)),
+            }),
+            Diagnostic::FrequencyShrinks => None,
+            Diagnostic::InvestmentExceedsHorizon { invest_days, .. } if invest_days.is_infinite() => Some("Add worked hours to the calendar in configuration (⚙).".to_string()),
+            Diagnostic::InvestmentExceedsHorizon { max_invest_taken_time, .. } => Some(match max_invest_taken_time {
+                Some(time) => format!("Reduce the investment to at most {} {}, or increase the projection time frame in configuration (⚙).",
+                                      round_down(*time), scenario.invest_taken_time_unit.plural(&scenario.conf_time_unit)),
+                None if scenario.start_delay_days > 0.0 => "Start the investment earlier, or increase the projection time frame in configuration (⚙).".to_string(),
+                None => "Increase the projection time frame in configuration (⚙).".to_string(),
+            }),
+            Diagnostic::SunsetBeforeBreakEven { day, .. } => Some(format!("This is only worth it for a process living at least {} days.", day.ceil())),
+            Diagnostic::BreakEvenAfterHorizon { day } => Some(format!("Increase the projection time frame to at least {} days.", day.ceil())),
+        }
+    }
+}
+
+/// Runs every check against the scenario, an empty result means the scenario pays off within the projection.
+pub fn diagnose(scenario: &Scenario) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    if scenario.pays_off() {
+        return diagnostics;
+    }
+    let break_even = scenario.intersection();
+    if scenario.executions_per_day() == 0.0 {
+        diagnostics.push(Diagnostic::RepeatCountZero { min_repeat_count: min_repeat_count(scenario) });
+    } else if scenario.adoption_percent <= 0.0 {
+        diagnostics.push(Diagnostic::NoAdoption { min_adoption_percent: min_adoption_percent(scenario) });
+    } else {
+        let without_failures = Scenario { before_failure: Failure::default(), after_failure: Failure::default(), ..scenario.clone() };
+        if scenario.after_execution_hours() >= scenario.before_execution_hours() && without_failures.after_execution_hours() < without_failures.before_execution_hours() {
+            diagnostics.push(Diagnostic::FailuresEatSaving { max_after_failure_rate: max_after_failure_rate(scenario) });
+        } else if scenario.after_execution_hours() >= scenario.before_execution_hours() {
+            diagnostics.push(Diagnostic::AfterNotFaster { max_after_taken_time: max_after_taken_time(scenario) });
+        } else if break_even == BreakEven::CapacitySaturated {
+            diagnostics.push(Diagnostic::CapacitySaturated { max_after_taken_time: max_after_taken_time(scenario) });
+        } else if break_even == BreakEven::NeverRecovered {
+            diagnostics.push(Diagnostic::FrequencyShrinks);
+        } else if break_even == BreakEven::NeverAfterNotFaster && scenario.induced_demand_factor() > 1.0
+            && (Scenario { induced_demand: InducedDemand::Unchanged, ..scenario.clone() }).intersection().point().is_some() {
+            diagnostics.push(Diagnostic::InducedDemandEatsSaving { max_induced_demand: max_induced_demand(scenario) });
+        } else if break_even == BreakEven::NeverAfterNotFaster && scenario.maintenance_per_day_in_hours() > 0.0 {
+            diagnostics.push(Diagnostic::MaintenanceExceedsSaving { max_maintenance_time: max_maintenance_time(scenario) });
+        }
+    }
+    let invest_days = scenario.invest_days();
+    let ship_day = scenario.ship_day();
+    if ship_day > scenario.scale_number_of_day as f64 {
+        // without any hour to invest, or when starting after the projection, only a zero investment would ship in time
+        let ships_in_time = invest_days.is_finite() && scenario.start_delay_days < scenario.scale_number_of_day as f64;
+        let max_invest_taken_time = if ships_in_time { max_invest_taken_time(scenario) } else { None };
+        diagnostics.push(Diagnostic::InvestmentExceedsHorizon { invest_days, ship_day, max_invest_taken_time });
+    } else if let BreakEven::NeverBeforeSunset { day, lifetime_days } = break_even {
+        diagnostics.push(Diagnostic::SunsetBeforeBreakEven { day, lifetime_days });
+    } else if let BreakEven::NeverWithinHorizon { day, .. } = break_even {
+        diagnostics.push(Diagnostic::BreakEvenAfterHorizon { day });
+    }
+    diagnostics
+}
+
+/// Repeating too often saturates the day, so the search doubles the count from the smallest one until it pays off, then bisects.
+fn min_repeat_count(scenario: &Scenario) -> Option<f64> {
+    let mut candidate = scenario.clone();
+    candidate.repeat_mode = RepeatMode::TimesPer;
+    let mut hi = MIN_REPEAT_COUNT;
+    loop {
+        candidate.repeat_count = hi;
+        if candidate.pays_off() {
+            break;
+        }
+        if hi >= MAX_REPEAT_COUNT {
+            return None;
+        }
+        hi = (hi * 2.0).min(MAX_REPEAT_COUNT);
+    }
+    let mut lo = hi / 2.0;
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (lo + hi) / 2.0;
+        candidate.repeat_count = mid;
+        if candidate.pays_off() {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(hi)
+}
+
+/// Adopting more pays off sooner, so this bisects the smallest share paying off, if a full adoption does.
+fn min_adoption_percent(scenario: &Scenario) -> Option<f64> {
+    let mut candidate = Scenario { adoption_percent: 100.0, ..scenario.clone() };
+    if !candidate.pays_off() {
+        return None;
+    }
+    let (mut lo, mut hi) = (0.0, 100.0);
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (lo + hi) / 2.0;
+        candidate.adoption_percent = mid;
+        if candidate.pays_off() {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(hi)
+}
+
+fn max_after_taken_time(scenario: &Scenario) -> Option<f64> {
+    largest_paying_value(scenario, 0.0, scenario.after_taken_time, |s, value| s.after_taken_time = value)
+}
+
+fn max_after_failure_rate(scenario: &Scenario) -> Option<f64> {
+    largest_paying_value(scenario, 0.0, scenario.after_failure.rate_percent, |s, value| s.after_failure.rate_percent = value)
+}
+
+fn max_induced_demand(scenario: &Scenario) -> Option<f64> {
+    match scenario.induced_demand {
+        InducedDemand::Unchanged => None,
+        InducedDemand::Multiplier(multiplier) => largest_paying_value(scenario, 1.0, multiplier, |s, value| s.induced_demand = InducedDemand::Multiplier(value)),
+        InducedDemand::Target(target) => largest_paying_value(scenario, scenario.executions_per_unit(), target, |s, value| s.induced_demand = InducedDemand::Target(value)),
+    }
+}
+
+fn max_maintenance_time(scenario: &Scenario) -> Option<f64> {
+    largest_paying_value(scenario, 0.0, scenario.maintenance_time, |s, value| s.maintenance_time = value)
+}
+
+fn max_invest_taken_time(scenario: &Scenario) -> Option<f64> {
+    largest_paying_value(scenario, 0.0, scenario.invest_taken_time, |s, value| s.invest_taken_time = value)
+}
+
+/// Bisects the largest value in [lo, hi] for which the scenario still pays off, assuming lower values pay off sooner.
+fn largest_paying_value(scenario: &Scenario, mut lo: f64, mut hi: f64, set: impl Fn(&mut Scenario, f64)) -> Option<f64> {
+    let mut candidate = scenario.clone();
+    set(&mut candidate, lo);
+    if !candidate.pays_off() {
+        return None;
+    }
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (lo + hi) / 2.0;
+        set(&mut candidate, mid);
+        if candidate.pays_off() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(lo)
+}
+
+/// What the per-execution hours account for on top of the durations, empty when they are just the durations.
+fn included_costs(scenario: &Scenario) -> Vec<&'static str> {
+    let mut included = vec![];
+    if scenario.before_failure.rate_percent > 0.0 || scenario.after_failure.rate_percent > 0.0 {
+        included.push("failures");
+    }
+    if scenario.multitasking.is_some() {
+        included.push("only the waiting not shared with other work");
+    }
+    if scenario.refocus.is_some() {
+        included.push("refocusing");
+    }
+    included
+}
+
+/// Hours per execution, to the tenth of a second below a minute.
+fn per_execution(hours: f64, scenario: &Scenario) -> String {
+    let conf = &scenario.conf_time_unit;
+    if hours < 1.0 / 60.0 {
+        return format!("{} seconds", (TimeUnit::Hours.to_seconds(hours, conf) * 10.0).round() / 10.0);
+    }
+    value_to_human_duration(hours, false, conf)
+}
+
+fn round_down(value: f64) -> f64 {
+    (value * 100.0).floor() / 100.0
+}
+
+fn round_up(value: f64) -> f64 {
+    (value * 100.0).ceil() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::{diagnose, Diagnostic};
+    use crate::calendar::Calendar;
+    use crate::model::{ConfTimeUnit, Failure, InducedDemand, Multitasking, Refocus, Scenario, TimeUnit};
+
+    #[test]
+    fn default_scenario_has_no_diagnostic() {
+        assert!(diagnose(&Scenario::default()).is_empty());
+    }
+
+    #[test]
+    fn after_not_faster() {
+        let scenario = Scenario { after_taken_time: 50.0, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::AfterNotFaster { max_after_taken_time: Some(max) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        // 160 executions per day during 90 days must recover 2 hours of investment
+        assert!(*max < 40.0 && *max > 39.0);
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn failures_eat_saving() {
+        // 5% of 2 hours is 6 minutes per execution, the 30 seconds saved do not make up for it
+        let scenario = Scenario { after_failure: Failure { rate_percent: 5.0, ..Failure::default() }, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::FailuresEatSaving { max_after_failure_rate: Some(max) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        assert!(*max < 30.0 / 7200.0 * 100.0 && *max > 0.4);
+        assert_eq!(diagnostics[0].cause(&scenario),
+                   "The optimised process fails 5% of the time, recovering makes an execution cost 6 minutes and 10 seconds on average, not lower than the 40 seconds of the current one.");
+
+        // slower and failing: the per-execution cost is what is compared
+        let slower = Scenario { after_taken_time: 50.0, before_failure: Failure { rate_percent: 0.1, ..Failure::default() }, ..Scenario::default() };
+        let diagnostics = diagnose(&slower);
+        assert!(matches!(diagnostics.first(), Some(Diagnostic::AfterNotFaster { .. })));
+        assert_eq!(diagnostics[0].cause(&slower),
+                   "Counting failures, an execution of the optimised process costs 50 seconds on average, which is not lower than the 47.2 seconds of the current one.");
+    }
+
+    #[test]
+    fn waiting_shared_with_other_work() {
+        // the current process is only waiting, and other work gets done meanwhile
+        let multitasking = Multitasking { before_waiting_percent: 100.0, after_waiting_percent: 0.0, efficiency_percent: 100.0, context_switch_time: 0.0, ..Multitasking::default() };
+        let scenario = Scenario { multitasking: Some(multitasking), ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        assert!(matches!(diagnostics.first(), Some(Diagnostic::AfterNotFaster { max_after_taken_time: None })));
+        assert_eq!(diagnostics[0].cause(&scenario),
+                   "Counting only the waiting not shared with other work, an execution of the optimised process costs 10 seconds on average, which is not lower than the 0 seconds of the current one.");
+    }
+
+    #[test]
+    fn refocusing_after() {
+        let scenario = Scenario { after_taken_time: 50.0, refocus: Some(Refocus { after: true, ..Refocus::default() }), ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        assert!(matches!(diagnostics.first(), Some(Diagnostic::AfterNotFaster { .. })));
+        assert_eq!(diagnostics[0].cause(&scenario),
+                   "Counting refocusing, an execution of the optimised process costs 15 minutes and 50 seconds on average, which is not lower than the 15 minutes and 40 seconds of the current one.");
+    }
+
+    #[test]
+    fn induced_demand_eats_saving() {
+        // 5 × 10s = 50s of the optimised process for each 40s of the current one
+        let scenario = Scenario { induced_demand: InducedDemand::Multiplier(5.0), ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::InducedDemandEatsSaving { max_induced_demand: Some(max) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        assert!(*max < 4.0 && *max > 3.9);
+        assert_eq!(diagnostics[0].suggestion(&scenario).unwrap(), format!("Repeat the optimised process at most ×{} as often.", (max * 100.0).floor() / 100.0));
+
+        let target = Scenario { induced_demand: InducedDemand::Target(100.0), ..Scenario::default() };
+        let Some(Diagnostic::InducedDemandEatsSaving { max_induced_demand: Some(max) }) = diagnose(&target).first().cloned() else {
+            panic!("unexpected diagnostics {:?}", diagnose(&target));
+        };
+        // 20 times per hour today
+        assert!((max / 20.0 - 3.95).abs() < 0.05);
+    }
+
+    #[test]
+    fn repeat_count_zero() {
+        let scenario = Scenario { repeat_count: 0.0, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::RepeatCountZero { min_repeat_count: Some(min) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        // 30 seconds saved per execution, 8 hours per day, 90 days: 2 hours are recovered with 1/3 execution per hour
+        assert!((*min - 1.0 / 3.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn no_adoption() {
+        let scenario = Scenario { adoption_percent: 0.0, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::NoAdoption { min_adoption_percent: Some(min) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        // the 2 hours invested, minus the 4/9 h not spent on the process meanwhile, recovered by day 90 saving 4/3 h a day when fully adopted
+        assert!((*min - 100.0 * (2.0 - 4.0 / 9.0) / (4.0 / 3.0 * 89.75)).abs() < 0.01);
+        assert_eq!(diagnostics[0].suggestion(&scenario).unwrap(), "Get at least 1.3% of the executions to go through the optimised process.");
+    }
+
+    #[test]
+    fn maintenance_exceeds_saving() {
+        // 2 hours of maintenance per day, only 1.33 hours saved per day
+        let scenario = Scenario { maintenance_time: 2.0, maintenance_period_unit: TimeUnit::Days, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::MaintenanceExceedsSaving { max_maintenance_time: Some(max) }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        assert!(*max < 4.0 / 3.0);
+        assert!(*max > 1.3);
+    }
+
+    #[test]
+    fn sunset_before_break_even() {
+        let scenario = Scenario { lifetime_days: Some(1.0), ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].suggestion(&scenario).unwrap(), "This is only worth it for a process living at least 2 days.");
+    }
+
+    #[test]
+    fn investment_exceeds_horizon() {
+        let scenario = Scenario { invest_taken_time: 100.0, invest_taken_time_unit: TimeUnit::Days, ..Scenario::default() };
+        let diagnostics = diagnose(&scenario);
+        let Some(Diagnostic::InvestmentExceedsHorizon { invest_days, max_invest_taken_time: Some(max), .. }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        assert_eq!(*invest_days, 100.0);
+        assert!(*max < 90.0);
+
+        // a short investment starting too late ships after the projection as well
+        let delayed = Scenario { start_delay_days: 95.0, invest_taken_time: 4.0, ..Scenario::default() };
+        let diagnostics = diagnose(&delayed);
+        let Some(Diagnostic::InvestmentExceedsHorizon { ship_day, max_invest_taken_time: None, .. }) = diagnostics.first() else {
+            panic!("unexpected diagnostics {:?}", diagnostics);
+        };
+        assert_eq!(*ship_day, 95.5);
+        assert_eq!(diagnostics[0].cause(&delayed), "Starting on day 95, the 0.5 days investment only ships on day 95.5, after the 90 days projection.");
+        assert_eq!(diagnostics[0].suggestion(&delayed).unwrap(), "Start the investment earlier, or increase the projection time frame in configuration (⚙).");
+
+        let no_worked_hour = Scenario { conf_time_unit: ConfTimeUnit { calendar: Calendar::full_time(0.0, 5), ..ConfTimeUnit::default() }, ..Scenario::default() };
+        let diagnostics = diagnose(&no_worked_hour);
+        // nor a single execution in a day
+        assert!(matches!(diagnostics[0], Diagnostic::RepeatCountZero { .. }));
+        assert_eq!(diagnostics[1].cause(&no_worked_hour), "Not a single hour can be put into the investment, it never ends.");
+        assert_eq!(diagnostics[1].suggestion(&no_worked_hour).unwrap(), "Add worked hours to the calendar in configuration (⚙).");
+    }
+}
@@ -0,0 +1,7 @@
+//! Headless part of the app: the ROI model and everything derived from it, usable without egui/eframe.
+pub mod calendar;
+pub mod model;
+pub mod breakdown;
+pub mod diagnostics;
+pub mod monte_carlo;
+pub mod sensitivity;
@@ -5,13 +5,22 @@ use eframe::{App, Frame, Renderer};
 use eframe::epaint::Color32;
 use eframe::Theme::Light;
 use egui::{ComboBox, Context, DragValue, Id, Label, RichText, Stroke, Ui, Vec2};
-use egui_plot::{AxisHints, Corner, Legend, Line, LineStyle, Plot, PlotMemory, PlotPoint, PlotPoints, Points};
+use egui_plot::{uniform_grid_spacer, AxisHints, Bar, BarChart, Corner, HPlacement, Legend, Line, LineStyle, Plot, PlotMemory, PlotPoint, PlotPoints, PlotUi, Points, Polygon, VLine};
+use too_busy_to_improve::calendar::{CalendarPreset, Date, Holiday, WEEKDAYS};
+use too_busy_to_improve::breakdown::{breakdown, to_plain_text};
+use too_busy_to_improve::diagnostics::diagnose;
+use too_busy_to_improve::model::{label_hours_to_hours_minutes, label_hours_to_minutes, value_to_human_duration, AdoptionRamp, BreakEven, ConfTimeUnit, CustomTimeUnit, Distribution, Estimate, Evaluation, Failure, InducedDemand, Multitasking, Refocus, FrequencyGrowth, GrowthStep, RepeatMode, Scenario, TimeUnit, Uncertainty};
+use too_busy_to_improve::monte_carlo::{simulate, Simulation};
+use too_busy_to_improve::sensitivity::{tornado, Outcome, Sensitivity};
 
 
 const BACKGROUND: Color32 = Color32::from_rgb(106, 49, 252);
 const BEFORE_COLOR: Color32 = Color32::from_rgb(255, 173, 0);
 const AFTER_COLOR: Color32 = Color32::from_rgb(75, 181, 67);
 const INVEST_COLOR: Color32 = Color32::from_rgb(6, 122, 199);
+const HISTOGRAM_BINS: usize = 30;
+/// Seconds the inputs have to stay unchanged before simulating again.
+const SIMULATION_SETTLE_SECONDS: f64 = 0.5;
 
 fn main() {
     #[cfg(not(target_arch = "wasm32"))]
@@ -30,264 +39,536 @@ fn main() {
     }
 }
 
-#[derive(Eq, PartialEq)]
-enum TimeUnit {
-    Seconds,
-    Minutes,
-    Hours,
-    Days,
-    Weeks,
-    Months,
-    Years,
+fn today() -> Date {
+    #[cfg(not(target_arch = "wasm32"))]
+    let millis = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|elapsed| elapsed.as_millis() as f64).unwrap_or(0.0);
+    #[cfg(target_arch = "wasm32")]
+    let millis = js_sys::Date::now();
+    Date::from_days((millis / 86_400_000.0).floor() as i64)
 }
 
-impl TimeUnit {
-    pub fn plural(&self) -> &str {
-        match self {
-            TimeUnit::Seconds => "seconds",
-            TimeUnit::Minutes => "minutes",
-            TimeUnit::Hours => "hours",
-            TimeUnit::Days => "days",
-            TimeUnit::Weeks => "weeks",
-            TimeUnit::Months => "months",
-            TimeUnit::Years => "years",
-        }
-    }
-    pub fn singular(&self) -> &str {
-        match self {
-            TimeUnit::Seconds => "second",
-            TimeUnit::Minutes => "minute",
-            TimeUnit::Hours => "hour",
-            TimeUnit::Days => "day",
-            TimeUnit::Weeks => "week",
-            TimeUnit::Months => "month",
-            TimeUnit::Years => "year",
-        }
-    }
-    pub fn to_seconds(&self, value: f64) -> f64 {
-        match self {
-            TimeUnit::Seconds => value,
-            TimeUnit::Minutes => value * 60.0,
-            TimeUnit::Hours => value * 60.0 * 60.0,
-            TimeUnit::Days => value * 60.0 * 60.0 * 24.0,
-            TimeUnit::Weeks => value * 60.0 * 60.0 * 24.0 * 7.0,
-            TimeUnit::Months => value * 60.0 * 60.0 * 24.0 * 30.0,
-            TimeUnit::Years => value * 60.0 * 60.0 * 24.0 * 365.0,
-        }
-    }
+struct MyApp {
+    scenario: Scenario,
+    /// Last simulation and the scenario it was run on, simulating on every frame would be wasteful.
+    simulation: Option<(Scenario, Simulation)>,
+    /// Scenario waiting to be simulated and since when it is unchanged, so that dragging a value does not simulate each frame.
+    pending_simulation: Option<(Scenario, f64)>,
+    /// ±% applied to each input by the sensitivity analysis.
+    sensitivity_variation: f64,
+    /// Labels the built-in units custom units are defined from, kept rather than built on every frame.
+    built_in_conf: ConfTimeUnit,
+}
 
-    pub fn to_hours(&self, value: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
-        match self {
-            TimeUnit::Seconds => value / 60.0 / 60.0,
-            TimeUnit::Minutes => value / 60.0,
-            TimeUnit::Hours => value,
-            TimeUnit::Days => value * conf_time_unit.number_of_hours_per_day as f64,
-            TimeUnit::Weeks => value * conf_time_unit.number_of_hours_per_day as f64 * conf_time_unit.number_of_day_per_week as f64,
-            TimeUnit::Months => value * conf_time_unit.number_of_hours_per_day as f64 * conf_time_unit.number_of_day_per_month as f64,
-            TimeUnit::Years => value * conf_time_unit.number_of_hours_per_day as f64 * conf_time_unit.number_of_day_per_month as f64 * 12.0,
+impl MyApp {
+    pub fn new() -> Self {
+        Self {
+            scenario: Scenario::default(),
+            simulation: None,
+            pending_simulation: None,
+            sensitivity_variation: 20.0,
+            built_in_conf: ConfTimeUnit::default(),
         }
     }
 
-    pub fn to_times_per_days(&self, value: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
-        match self {
-            TimeUnit::Seconds => value * 60.0 * 60.0 * conf_time_unit.number_of_hours_per_day as f64,
-            TimeUnit::Minutes => value * 60.0 * conf_time_unit.number_of_hours_per_day as f64,
-            TimeUnit::Hours => value * conf_time_unit.number_of_hours_per_day as f64,
-            TimeUnit::Days => value,
-            TimeUnit::Weeks => value / conf_time_unit.number_of_day_per_week as f64,
-            TimeUnit::Months => value / (conf_time_unit.number_of_day_per_month as f64),
-            TimeUnit::Years => value / (conf_time_unit.number_of_day_per_month as f64 * 12.0),
+    fn refresh_simulation(&mut self, ctx: &Context) {
+        let Some(uncertainty) = self.scenario.uncertainty.as_ref() else {
+            self.simulation = None;
+            self.pending_simulation = None;
+            return;
+        };
+        if matches!(&self.simulation, Some((scenario, _)) if *scenario == self.scenario) {
+            self.pending_simulation = None;
+            return;
+        }
+        let now = ctx.input(|input| input.time);
+        match &self.pending_simulation {
+            Some((scenario, since)) if *scenario == self.scenario => {
+                if now - since >= SIMULATION_SETTLE_SECONDS {
+                    self.simulation = Some((self.scenario.clone(), simulate(&self.scenario, uncertainty)));
+                    self.pending_simulation = None;
+                    return;
+                }
+            }
+            _ => self.pending_simulation = Some((self.scenario.clone(), now)),
         }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(SIMULATION_SETTLE_SECONDS));
     }
-}
-
-struct ConfTimeUnit {
-    number_of_hours_per_day: u8,
-    number_of_day_per_week: u8,
-    number_of_day_per_month: u8,
-}
-
-struct MyApp {
-    before_taken_time: f64,
-    before_taken_time_unit: TimeUnit,
-    after_taken_time: f64,
-    after_taken_time_unit: TimeUnit,
-    invest_taken_time: f64,
-    invest_taken_time_unit: TimeUnit,
-    repeat_count: usize,
-    repeat_count_time_unit: TimeUnit,
-    scale_number_of_day: usize,
-    conf_time_unit: ConfTimeUnit,
-}
 
-impl MyApp {
-    pub fn new() -> Self {
-        Self {
-            before_taken_time: 40.0,
-            before_taken_time_unit: TimeUnit::Seconds,
-            after_taken_time: 10.0,
-            after_taken_time_unit: TimeUnit::Seconds,
-            invest_taken_time: 2.0,
-            invest_taken_time_unit: TimeUnit::Hours,
-            repeat_count: 20,
-            repeat_count_time_unit: TimeUnit::Hours,
-            scale_number_of_day: 90,
-
-            conf_time_unit: ConfTimeUnit {
-                number_of_hours_per_day: 8,
-                number_of_day_per_week: 5,
-                number_of_day_per_month: 22,
-            },
+    fn confidence_band(plot_ui: &mut PlotUi, (low, high): &(Vec<[f64; 2]>, Vec<[f64; 2]>), color: Color32) {
+        // one quad per interval: polygons are only filled properly when convex
+        for i in 1..low.len() {
+            let quad = vec![low[i - 1], low[i], high[i], high[i - 1]];
+            plot_ui.polygon(Polygon::new(PlotPoints::from(quad)).fill_color(color.gamma_multiply(0.15)).stroke(Stroke::NONE));
         }
     }
 
-    fn before_line(&self) -> Line {
-        Line::new(PlotPoints::from_parametric_callback(|t| self.time_taken_per_day_in_hours(t, &self.before_taken_time_unit, self.before_taken_time),
-                                                       0.0..=(self.scale_number_of_day as f64),
-                                                       self.scale_number_of_day,
-        ))
+    fn before_line(evaluation: &Evaluation) -> Line {
+        Line::new(PlotPoints::from(evaluation.before_series.clone()))
             .color(BEFORE_COLOR)
             .style(LineStyle::Solid)
             .name("before")
     }
-    fn invest_time_line(&self, invest_time_in_hours: f64, days_needed: f64) -> Line {
-        Line::new(PlotPoints::from_parametric_callback(|t| {
-            let hours_per_day = self.conf_time_unit.number_of_hours_per_day as f64;
-            let x = invest_time_in_hours / (hours_per_day * t);
-            let res = if x < 1.0 {
-                let hours_previous_days = (t - 1.0) * hours_per_day;
-                hours_previous_days + invest_time_in_hours - hours_previous_days
-            } else {
-                t * hours_per_day
-            };
-            (t, res)
-        },
-                                                       0.0..=(days_needed),
-                                                       2.max(days_needed as usize),
-        ))
+    fn invest_time_line(evaluation: &Evaluation) -> Line {
+        Line::new(PlotPoints::from(evaluation.invest_series.clone()))
             .color(INVEST_COLOR)
             .style(LineStyle::Solid)
             .name("invested time")
     }
-    fn after_line(&self, invest_time_in_hours: f64, after_start_at_day: f64) -> Line {
-        let o = self.time_taken_per_day_in_hours(after_start_at_day, &self.after_taken_time_unit, self.after_taken_time).1;
-        Line::new(PlotPoints::from_parametric_callback(|t| {
-            let res = self.time_taken_per_day_in_hours(t, &self.after_taken_time_unit, self.after_taken_time);
-            (t, invest_time_in_hours + res.1 - o)
-        },
-                                                       (after_start_at_day)..=(self.scale_number_of_day as f64),
-                                                       self.scale_number_of_day,
-        ))
+    /// Investment and after lines of a variant of the scenario, to compare with the main one.
+    fn variant_line(evaluation: &Evaluation, style: LineStyle, name: &str) -> Line {
+        Line::new(PlotPoints::from([evaluation.invest_series.clone(), evaluation.after_series.clone()].concat()))
+            .color(AFTER_COLOR.gamma_multiply(0.5))
+            .style(style)
+            .name(name)
+    }
+    fn after_line(evaluation: &Evaluation) -> Line {
+        Line::new(PlotPoints::from(evaluation.after_series.clone()))
             .color(AFTER_COLOR)
             .style(LineStyle::Solid)
             .name("after")
     }
 
-    fn time_taken_per_day_in_hours(&self, t: f64, time_unit: &TimeUnit, time_taken: f64) -> (f64, f64) {
-        let input = time_unit.to_hours(time_taken, &self.conf_time_unit);
-        (t, 24.0_f64.min(input * self.repeat_count_time_unit.to_times_per_days(self.repeat_count as f64, &self.conf_time_unit)) * t)
+    fn calendar_ui(&mut self, ui: &mut Ui) {
+        let calendar = &mut self.scenario.conf_time_unit.calendar;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Use the calendar of "));
+            let preset = ComboBox::new("calendar_preset", "").selected_text("...");
+            styled_component(ui, |ui| {
+                preset.show_ui(ui, |ui| {
+                    for preset in CalendarPreset::ALL {
+                        if ui.selectable_label(false, preset.name()).clicked() {
+                            *calendar = preset.calendar();
+                        }
+                    }
+                })
+            });
+        });
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Worked hours: "));
+            for (weekday, hours) in WEEKDAYS.iter().zip(calendar.hours_per_weekday.iter_mut()) {
+                ui.label(text(&format!("{} ", weekday)));
+                styled_component(ui, |ui| { ui.add(DragValue::new(hours).range(0.0..=24.0).speed(0.5)) });
+                ui.add_space(5.0);
+            }
+        });
+        // like the other ranges, prevent a configuration the projection cannot work with: at least one day has to be worked
+        if calendar.number_of_day_per_week() == 0.0 {
+            calendar.hours_per_weekday[0] = 1.0;
+        }
+        // same for vacations: at least one day a year has to be worked
+        let max_vacation_days = (calendar.number_of_day_per_year() + calendar.vacation_days_per_year - 1.0).floor().max(0.0);
+        calendar.vacation_days_per_year = calendar.vacation_days_per_year.min(max_vacation_days);
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Every year there are "));
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut calendar.vacation_days_per_year).range(0.0..=max_vacation_days).speed(1.0)) });
+            ui.label(text(&format!(" vacation days and {} public holidays", calendar.public_holidays.len())));
+        });
+        ui.collapsing(text("Public holidays"), |ui| {
+            let mut removed_holiday = None;
+            for (index, holiday) in calendar.public_holidays.iter_mut().enumerate() {
+                ui.horizontal_wrapped(|ui| {
+                    styled_component(ui, |ui| { ui.add(egui::TextEdit::singleline(&mut holiday.name).desired_width(150.0)) });
+                    ui.label(text(" on "));
+                    styled_component(ui, |ui| { ui.add(DragValue::new(&mut holiday.day).range(1.0..=31.0).speed(1.0)) });
+                    ui.label(text("/"));
+                    styled_component(ui, |ui| { ui.add(DragValue::new(&mut holiday.month).range(1.0..=12.0).speed(1.0)) });
+                    ui.add_space(5.0);
+                    if ui.button(text("🗑")).clicked() {
+                        removed_holiday = Some(index);
+                    }
+                });
+            }
+            if let Some(index) = removed_holiday {
+                calendar.public_holidays.remove(index);
+            }
+            if ui.button(text("➕ Add a public holiday")).clicked() {
+                calendar.public_holidays.push(Holiday { name: "Holiday".to_string(), month: 1, day: 1 });
+            }
+        });
+        ui.add_space(5.0);
+        let conf_time_unit = &self.scenario.conf_time_unit;
+        ui.label(text(&format!("1 day is {:.1} hours, 1 week is {} days, 1 month is {:.1} days and 1 year is {:.1} days of work.",
+                               conf_time_unit.number_of_hours_per_day(), conf_time_unit.number_of_day_per_week(),
+                               conf_time_unit.number_of_day_per_month(), conf_time_unit.number_of_day_per_year())));
     }
 
-    fn intersection(&self, invest_time_in_hours: f64, after_invest_time: f64) -> (f64, f64) {
-        // before line: y = ax + b
-        let x = 1.0;
-        let b = 0.0;
-        let a = self.time_taken_per_day_in_hours(x, &self.before_taken_time_unit, self.before_taken_time).1;
-        let o = self.time_taken_per_day_in_hours(after_invest_time, &self.before_taken_time_unit, self.before_taken_time).1;
-        if o >= invest_time_in_hours {
-            return (after_invest_time, invest_time_in_hours)
+    fn subject(&self) -> String {
+        if self.scenario.people_count > 1 {
+            format!("your team of {} people", self.scenario.people_count)
+        } else {
+            "you".to_string()
         }
+    }
 
-        // after line: y1 = a1x + b1 - o
-        let o1 = self.time_taken_per_day_in_hours(after_invest_time, &self.after_taken_time_unit, self.after_taken_time).1;
-        let b1 = invest_time_in_hours - o1;
-        if b1 <= 0.0 {
-            return (-1.0,-1.0)
+    fn break_even_date_ui(&self, ui: &mut Ui, day: f64) {
+        if let Some(date) = self.scenario.date_of_day(day) {
+            ui.heading(", break-even on ");
+            ui.heading(RichText::new(date.format_long()).strong());
         }
-        let a1 = self.time_taken_per_day_in_hours(x, &self.after_taken_time_unit, self.after_taken_time).1;
-        let x_intersection = (b1 - b) / (a - a1);
-        // println!("a: {}, a1: {}, o: {}, o1: {}, b: {}, b1: {}, x_inter: {}, invest_time_in_hours: {}", a, a1, o, o1, b, b1, x_intersection, invest_time_in_hours);
-        (x_intersection, a * x_intersection + b)
     }
 
-    fn label_hours_to_minutes(val: f64, short: bool) -> String {
-        let seconds = TimeUnit::Hours.to_seconds(val) as usize;
-        let minutes = seconds / 60;
-        let remaining_seconds = seconds % 60;
-        if remaining_seconds > 0 {
-            if short {
-                format!("{}m {}s", minutes, remaining_seconds)
-            } else {
-                format!("{} minutes and {} seconds", minutes, remaining_seconds)
-            }
-        } else {
-            if short {
-                format!("{}m", minutes)
-            } else {
-                format!("{} minutes", minutes)
+    /// "14 March 2026" when the project has a start date, "day 12.3" otherwise.
+    fn day_label(&self, day: f64) -> String {
+        self.scenario.date_of_day(day).map_or(format!("day {:.1}", day), |date| date.format_long())
+    }
+
+    fn risk_adjusted_ui(&self, ui: &mut Ui, evaluation: &Evaluation, risk_adjusted: &Evaluation) {
+        let optimistic = evaluation.break_even.point().map_or("never".to_string(), |(day, _)| self.day_label(day));
+        let expected = match risk_adjusted.break_even.point() {
+            Some((day, _)) => format!("breaks even on {}", self.day_label(day)),
+            None => "never breaks even".to_string(),
+        };
+        ui.label(text(&format!("With {} % chances of success and an investment taking ×{} longer, the expected value {} (optimistic: {}) and saves {}.",
+                               self.scenario.success_probability_percent, self.scenario.invest_overrun_factor, expected, optimistic,
+                               value_to_human_duration(risk_adjusted.saved_hours, false, &self.scenario.conf_time_unit))));
+    }
+
+    fn discounted_ui(&self, ui: &mut Ui, evaluation: &Evaluation) {
+        let (Some(discounted), Some(percent)) = (evaluation.discounted.as_ref(), self.scenario.discount_rate_percent_per_year) else {
+            return;
+        };
+        let conf = &self.scenario.conf_time_unit;
+        let net_saved_hours = discounted.undiscounted_net_saved_hours;
+        let break_even = match (discounted.break_even_day, evaluation.break_even.point()) {
+            (Some(day), Some((undiscounted_day, _))) => {
+                format!("Break-even on {} instead of {}", self.day_label(day), self.day_label(undiscounted_day))
             }
+            _ => "It never breaks even".to_string(),
+        };
+        let cost_model = &self.scenario.cost_model;
+        let money = |hours: f64| if cost_model.enabled() { format!(" ({})", cost_model.format(cost_model.cost(hours))) } else { String::new() };
+        ui.label(text(&format!("Discounted at {} % per year: {}, with a net saving of {}{} instead of {}{} after {} days.", percent, break_even,
+                               value_to_human_duration(discounted.net_saved_hours, false, conf), money(discounted.net_saved_hours),
+                               value_to_human_duration(net_saved_hours, false, conf), money(net_saved_hours), self.scenario.scale_number_of_day)));
+    }
+
+    fn money_ui(&self, ui: &mut Ui, evaluation: &Evaluation) {
+        let cost_model = &self.scenario.cost_model;
+        if !cost_model.enabled() {
+            return;
         }
+        ui.horizontal_wrapped(|ui| {
+            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+            ui.label(text("Investment costs "));
+            ui.label(text(&cost_model.format(evaluation.invest_cost)).strong());
+            ui.label(text(", this action costs "));
+            ui.label(text_with_color(&cost_model.format(evaluation.before_daily_cost), BEFORE_COLOR).strong());
+            ui.label(text(" per day today and would cost "));
+            ui.label(text_with_color(&cost_model.format(evaluation.after_daily_cost), AFTER_COLOR).strong());
+            ui.label(text(" per day once optimized. "));
+            ui.label(text(&format!("After {} days you would have saved ", self.scenario.scale_number_of_day)));
+            ui.label(text(&cost_model.format(evaluation.saved_money)).strong());
+            ui.label(text(&format!(" ({} once the investment is paid back).", cost_model.format(evaluation.saved_money - evaluation.invest_cost))));
+            if let Some((day, hours)) = evaluation.break_even.point() {
+                let day = match self.scenario.date_of_day(day) {
+                    Some(date) => date.format_long(),
+                    None => format!("day {:.1}", day),
+                };
+                ui.label(text(&format!(" Break-even on {}, once {} have been spent.", day, cost_model.format(cost_model.cost(hours)))));
+            }
+        });
     }
 
-    fn label_hours_to_hours_minutes(val: f64, short: bool) -> String {
-        let seconds = TimeUnit::Hours.to_seconds(val) as usize;
-        let hours = seconds / 60 / 60;
-        let minutes = seconds / 60 % 60;
-        if minutes > 0 {
-            if short {
-                format!("{}h {}m", hours, minutes)
-            } else {
-                format!("{} hours and {} minutes", hours, minutes)
+    fn frequency_growth_ui(&mut self, ui: &mut Ui) {
+        let growth = &mut self.scenario.frequency_growth;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Over time, this frequency "));
+            let label = match growth {
+                FrequencyGrowth::Constant => "stays the same",
+                FrequencyGrowth::PercentPerMonth(_) => "changes by",
+                FrequencyGrowth::Schedule(_) => "follows a schedule",
+            };
+            let growth_combo = ComboBox::new("frequency_growth", "").selected_text(label);
+            styled_component(ui, |ui| {
+                growth_combo.show_ui(ui, |ui| {
+                    if ui.selectable_label(matches!(growth, FrequencyGrowth::Constant), "stays the same").clicked() {
+                        *growth = FrequencyGrowth::Constant;
+                    }
+                    if ui.selectable_label(matches!(growth, FrequencyGrowth::PercentPerMonth(_)), "changes by").clicked() {
+                        *growth = FrequencyGrowth::PercentPerMonth(5.0);
+                    }
+                    if ui.selectable_label(matches!(growth, FrequencyGrowth::Schedule(_)), "follows a schedule").clicked() {
+                        *growth = FrequencyGrowth::Schedule(vec![GrowthStep { from_day: 30.0, multiplier: 2.0 }]);
+                    }
+                })
+            });
+            if let FrequencyGrowth::PercentPerMonth(percent) = growth {
+                ui.add_space(5.0);
+                styled_component(ui, |ui| { ui.add(DragValue::new(percent).range(-100.0..=1000.0).speed(0.5)) });
+                ui.label(text(" % per month"));
             }
-        } else {
-            if short {
-                format!("{}h", hours)
-            } else {
-                format!("{} hours", hours)
+            ui.label(text("."));
+        });
+        if let FrequencyGrowth::Schedule(steps) = growth {
+            let mut removed_step = None;
+            for (index, step) in steps.iter_mut().enumerate() {
+                ui.add_space(5.0);
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(text("From day "));
+                    styled_component(ui, |ui| { ui.add(DragValue::new(&mut step.from_day).range(0.0..=10000.0).speed(1.0)) });
+                    ui.label(text(" it is repeated ×"));
+                    styled_component(ui, |ui| { ui.add(DragValue::new(&mut step.multiplier).range(0.0..=1000.0).speed(0.05)) });
+                    ui.label(text(" as often"));
+                    ui.add_space(5.0);
+                    if ui.button(text("🗑")).clicked() {
+                        removed_step = Some(index);
+                    }
+                });
+            }
+            if let Some(index) = removed_step {
+                steps.remove(index);
+            }
+            ui.add_space(5.0);
+            if ui.button(text("➕ Add a step")).clicked() {
+                let from_day = steps.iter().map(|step| step.from_day).fold(0.0, f64::max) + 30.0;
+                steps.push(GrowthStep { from_day, multiplier: 1.0 });
             }
         }
     }
 
-    fn label_hours_to_days_hours(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
-        let seconds = TimeUnit::Hours.to_seconds(val) as usize;
-        let days = seconds / 60 / 60 / conf.number_of_hours_per_day as usize;
-        let hours = seconds / 60 / 60 % conf.number_of_hours_per_day as usize;
-        if short {
-            if hours == 0 {
-                format!("{}d", days)
-            } else {
-                format!("{}d {}h", days, hours)
+    fn adoption_ui(&mut self, ui: &mut Ui) {
+        let ramp = &mut self.scenario.adoption_ramp;
+        ui.horizontal_wrapped(|ui| {
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.adoption_percent).range(0.0..=100.0).speed(1.0)) });
+            ui.label(text(" % of the executions would use it, reached "));
+            let label = match ramp {
+                AdoptionRamp::Immediate => "immediately",
+                AdoptionRamp::Linear { .. } => "linearly over",
+                AdoptionRamp::Exponential { .. } => "exponentially over",
+            };
+            let ramp_combo = ComboBox::new("adoption_ramp", "").selected_text(label);
+            styled_component(ui, |ui| {
+                ramp_combo.show_ui(ui, |ui| {
+                    if ui.selectable_label(matches!(ramp, AdoptionRamp::Immediate), "immediately").clicked() {
+                        *ramp = AdoptionRamp::Immediate;
+                    }
+                    if ui.selectable_label(matches!(ramp, AdoptionRamp::Linear { .. }), "linearly over").clicked() {
+                        *ramp = AdoptionRamp::Linear { days: 20.0 };
+                    }
+                    if ui.selectable_label(matches!(ramp, AdoptionRamp::Exponential { .. }), "exponentially over").clicked() {
+                        *ramp = AdoptionRamp::Exponential { days: 20.0 };
+                    }
+                })
+            });
+            if let AdoptionRamp::Linear { days } | AdoptionRamp::Exponential { days } = ramp {
+                ui.add_space(5.0);
+                styled_component(ui, |ui| { ui.add(DragValue::new(days).range(0.0..=10000.0).speed(1.0)) });
+                ui.label(text(" days"));
             }
-        } else {
-            if hours == 0 {
-                format!("{} days", days)
-            } else {
-                format!("{} days and {} hours", days, hours)
+            ui.label(text("."));
+        });
+    }
+
+    fn induced_demand_ui(&mut self, ui: &mut Ui) {
+        let executions_per_unit = self.scenario.executions_per_unit();
+        let unit = self.scenario.repeat_count_time_unit.singular(&self.scenario.conf_time_unit).to_string();
+        let induced_demand = &mut self.scenario.induced_demand;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Being cheaper, it would be repeated "));
+            let label = match induced_demand {
+                InducedDemand::Unchanged => "as often",
+                InducedDemand::Multiplier(_) => "more often, ×",
+                InducedDemand::Target(_) => "this many times per",
+            };
+            let induced_demand_combo = ComboBox::new("induced_demand", "").selected_text(label);
+            styled_component(ui, |ui| {
+                induced_demand_combo.show_ui(ui, |ui| {
+                    if ui.selectable_label(matches!(induced_demand, InducedDemand::Unchanged), "as often").clicked() {
+                        *induced_demand = InducedDemand::Unchanged;
+                    }
+                    if ui.selectable_label(matches!(induced_demand, InducedDemand::Multiplier(_)), "more often, ×").clicked() {
+                        *induced_demand = InducedDemand::Multiplier(2.0);
+                    }
+                    if ui.selectable_label(matches!(induced_demand, InducedDemand::Target(_)), "this many times per").clicked() {
+                        *induced_demand = InducedDemand::Target(executions_per_unit * 2.0);
+                    }
+                })
+            });
+            match induced_demand {
+                InducedDemand::Unchanged => {}
+                InducedDemand::Multiplier(multiplier) => {
+                    ui.add_space(5.0);
+                    styled_component(ui, |ui| { ui.add(DragValue::new(multiplier).range(0.0..=1000.0).speed(0.05)) });
+                }
+                InducedDemand::Target(target) => {
+                    ui.add_space(5.0);
+                    ui.label(text(&format!("{}: ", unit)));
+                    styled_component(ui, |ui| { ui.add(DragValue::new(target).range(0.0..=100000.0).speed(1.0)) });
+                }
             }
+            ui.label(text("."));
+        });
+    }
+
+    fn multitasking_ui(&mut self, ui: &mut Ui) {
+        let mut waiting = self.scenario.multitasking.is_some();
+        if ui.checkbox(&mut waiting, text("Part of these durations is spent waiting (CI, builds...)")).changed() {
+            self.scenario.multitasking = if waiting { Some(Multitasking::default()) } else { None };
         }
+        let conf = &self.scenario.conf_time_unit;
+        let Some(multitasking) = self.scenario.multitasking.as_mut() else {
+            return;
+        };
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Waiting is "));
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut multitasking.before_waiting_percent).range(0.0..=100.0).speed(1.0)) });
+            ui.label(text(" % of it today and "));
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut multitasking.after_waiting_percent).range(0.0..=100.0).speed(1.0)) });
+            ui.label(text(" % once optimized."));
+        });
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Meanwhile "));
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut multitasking.efficiency_percent).range(0.0..=100.0).speed(1.0)) });
+            ui.label(text(" % of the waiting time is put to good use, and switching back and forth costs "));
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut multitasking.context_switch_time).range(0.0..=10000.0).speed(0.5)) });
+            ui.add_space(5.0);
+            let context_switch_time_unit = ComboBox::new("context_switch_time_unit", "").selected_text(multitasking.context_switch_time_unit.plural(conf));
+            styled_component(ui, |ui| {
+                context_switch_time_unit.show_ui(ui, |ui| {
+                    ui.selectable_value(&mut multitasking.context_switch_time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural(conf)).changed()
+                        || ui.selectable_value(&mut multitasking.context_switch_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(conf)).changed()
+                        || custom_time_units_selectable_values(ui, &mut multitasking.context_switch_time_unit, conf, true)
+                })
+            });
+            ui.label(text("."));
+        });
     }
 
-    fn value_to_human_duration(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
-        if val < 0.016 {
-            if short {
-                format!("{}s", TimeUnit::Hours.to_seconds(val))
-            } else {
-                format!("{} seconds", TimeUnit::Hours.to_seconds(val))
+    fn refocus_ui(&mut self, ui: &mut Ui) {
+        let conf = &self.scenario.conf_time_unit;
+        ui.horizontal_wrapped(|ui| {
+            let mut interrupting = self.scenario.refocus.is_some();
+            if ui.checkbox(&mut interrupting, text("It interrupts other work, getting back to it takes ")).changed() {
+                self.scenario.refocus = if interrupting { Some(Refocus::default()) } else { None };
             }
-        } else if val < 1.0 {
-            Self::label_hours_to_minutes(val, short)
-        } else if val < conf.number_of_hours_per_day as f64 {
-            Self::label_hours_to_hours_minutes(val, short)
-        } else {
-            Self::label_hours_to_days_hours(val, short, conf)
+            let Some(refocus) = self.scenario.refocus.as_mut() else {
+                ui.label(text("some time."));
+                return;
+            };
+            styled_component(ui, |ui| { ui.add(DragValue::new(&mut refocus.time).range(0.0..=10000.0).speed(0.5)) });
+            ui.add_space(5.0);
+            let refocus_time_unit = ComboBox::new("refocus_time_unit", "").selected_text(refocus.time_unit.plural(conf));
+            styled_component(ui, |ui| {
+                refocus_time_unit.show_ui(ui, |ui| {
+                    ui.selectable_value(&mut refocus.time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural(conf)).changed()
+                        || ui.selectable_value(&mut refocus.time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(conf)).changed()
+                        || ui.selectable_value(&mut refocus.time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(conf)).changed()
+                        || custom_time_units_selectable_values(ui, &mut refocus.time_unit, conf, true)
+                })
+            });
+            ui.label(text(" each time."));
+            ui.add_space(10.0);
+            ui.checkbox(&mut refocus.after, text("Even once optimized"));
+        });
+    }
+
+    fn uncertainty_ui(&mut self, ui: &mut Ui) {
+        let mut uncertain = self.scenario.uncertainty.is_some();
+        if ui.checkbox(&mut uncertain, text("My estimates are uncertain")).changed() {
+            self.scenario.uncertainty = if uncertain { Some(Uncertainty::around(&self.scenario, 0.25)) } else { None };
+        }
+        let conf = &self.scenario.conf_time_unit;
+        let Some(uncertainty) = self.scenario.uncertainty.as_mut() else {
+            return;
+        };
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(text("Values are drawn from a "));
+            let distribution_label = |distribution: Distribution| match distribution {
+                Distribution::Triangular => "triangular",
+                Distribution::Pert => "PERT",
+            };
+            let distribution = ComboBox::new("distribution", "").selected_text(distribution_label(uncertainty.distribution));
+            styled_component(ui, |ui| {
+                distribution.show_ui(ui, |ui| {
+                    for value in [Distribution::Pert, Distribution::Triangular] {
+                        ui.selectable_value(&mut uncertainty.distribution, value, distribution_label(value));
+                    }
+                })
+            });
+            ui.label(text(&format!(" distribution over {} simulations.", uncertainty.runs)));
+        });
+        let repeat_unit = match self.scenario.repeat_mode {
+            RepeatMode::TimesPer => format!("times per {}", self.scenario.repeat_count_time_unit.singular(conf)),
+            RepeatMode::Every => self.scenario.repeat_count_time_unit.plural(conf).to_string(),
+        };
+        estimate_ui(ui, "I repeat it between ", &mut uncertainty.repeat_count, self.scenario.repeat_count, &repeat_unit);
+        estimate_ui(ui, "It takes between ", &mut uncertainty.before_taken_time, self.scenario.before_taken_time, self.scenario.before_taken_time_unit.plural(conf));
+        estimate_ui(ui, "Optimised, it would take between ", &mut uncertainty.after_taken_time, self.scenario.after_taken_time, self.scenario.after_taken_time_unit.plural(conf));
+        estimate_ui(ui, "Optimising would take between ", &mut uncertainty.invest_taken_time, self.scenario.invest_taken_time, self.scenario.invest_taken_time_unit.plural(conf));
+    }
+
+    fn simulation_ui(&self, ui: &mut Ui) {
+        let Some((_, simulation)) = self.simulation.as_ref() else {
+            return;
+        };
+        let day_label = |day: Option<f64>| match day {
+            Some(day) => match self.scenario.date_of_day(day) {
+                Some(date) => date.format_long(),
+                None => format!("day {:.1}", day),
+            },
+            None => "never".to_string(),
+        };
+        ui.horizontal_wrapped(|ui| {
+            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+            ui.label(text(&format!("{:.0}% of {} simulations pay off within {} days. ",
+                                   simulation.pays_off_ratio(self.scenario.scale_number_of_day) * 100.0, simulation.runs, self.scenario.scale_number_of_day)));
+            ui.label(text("Break-even P50: "));
+            ui.label(text(&day_label(simulation.break_even_percentile(0.5))).strong());
+            ui.label(text(", P90: "));
+            ui.label(text(&day_label(simulation.break_even_percentile(0.9))).strong());
+        });
+        ui.collapsing(text("📊 Break-even distribution"), |ui| {
+            let max_day = self.scenario.scale_number_of_day as f64;
+            let width = max_day / HISTOGRAM_BINS as f64;
+            let bars = simulation.histogram(HISTOGRAM_BINS, max_day).iter().map(|[day, count]| Bar::new(*day, *count).width(width)).collect();
+            Plot::new("break_even_histogram").height(150.0).allow_drag(false).allow_zoom(false).allow_scroll(false)
+                .show(ui, |plot_ui| plot_ui.bar_chart(BarChart::new(bars).color(INVEST_COLOR).name("simulations breaking even")));
+        });
+    }
+
+    fn sensitivity_ui(&mut self, ui: &mut Ui) {
+        ui.collapsing(text("🌪 Sensitivity"), |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(text("What if each input was off by ±"));
+                styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.sensitivity_variation).range(1.0..=90.0).speed(1.0)) });
+                ui.label(text("%?"));
+            });
+            let tornado = tornado(&self.scenario, self.sensitivity_variation / 100.0);
+            let variation = self.sensitivity_variation;
+            ui.columns(2, |columns| {
+                columns[0].label(text("Break-even day"));
+                tornado_plot(&mut columns[0], "break_even_tornado", tornado.ranked_by(Sensitivity::break_even_swing), &tornado.base,
+                             variation, |outcome| outcome.break_even_day);
+                columns[1].label(text("Saved hours"));
+                tornado_plot(&mut columns[1], "saved_hours_tornado", tornado.ranked_by(Sensitivity::saved_hours_swing), &tornado.base,
+                             variation, |outcome| Some(outcome.saved_hours));
+            });
+        });
+    }
+
+    fn diagnostics_ui(&self, ui: &mut Ui) {
+        let diagnostics = diagnose(&self.scenario);
+        if diagnostics.is_empty() {
+            return;
         }
+        egui::CollapsingHeader::new(text("🔍 Why?")).default_open(true).show(ui, |ui| {
+            for diagnostic in diagnostics {
+                ui.label(text(&format!("• {}", diagnostic.cause(&self.scenario))));
+                if let Some(suggestion) = diagnostic.suggestion(&self.scenario) {
+                    ui.label(text_with_color(&format!("  {}", suggestion), AFTER_COLOR));
+                }
+            }
+        });
     }
 }
 
 impl App for MyApp {
     fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
-        let invest_time_in_hours = self.invest_taken_time_unit.to_hours(self.invest_taken_time, &self.conf_time_unit);
-        let after_invest_time = invest_time_in_hours / self.conf_time_unit.number_of_hours_per_day as f64;
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::TopBottomPanel::top("top")
                 .resizable(false)
@@ -298,172 +579,461 @@ impl App for MyApp {
                     ui.style_mut().visuals.selection.stroke = Stroke::new(1.5, Color32::WHITE);
                     ui.style_mut().visuals.text_cursor.stroke = Stroke::new(1.5, Color32::WHITE);
                     let (_response_repeat_count, _response_repeat_count_unit) = ui.horizontal_wrapped(|ui| {
-                        ui.label(text("I repeat "));
-                        let text_edit_repeat_count = DragValue::new(&mut self.repeat_count).range(0.0..=10000.0).speed(1.0);
+                        let repeat_mode_ui = |ui: &mut Ui, repeat_mode: &mut RepeatMode| {
+                            let repeat_mode_combo = ComboBox::new("repeat_mode", "").selected_text(if *repeat_mode == RepeatMode::Every { "every" } else { "times per" });
+                            styled_component(ui, |ui| {
+                                repeat_mode_combo.show_ui(ui, |ui| {
+                                    ui.selectable_value(repeat_mode, RepeatMode::TimesPer, "times per").changed()
+                                        || ui.selectable_value(repeat_mode, RepeatMode::Every, "every").changed()
+                                })
+                            });
+                        };
+                        let every = self.scenario.repeat_mode == RepeatMode::Every;
+                        if every {
+                            ui.label(text("I repeat this action "));
+                            repeat_mode_ui(ui, &mut self.scenario.repeat_mode);
+                            ui.add_space(5.0);
+                        } else {
+                            ui.label(text("I repeat "));
+                        }
+                        let text_edit_repeat_count = DragValue::new(&mut self.scenario.repeat_count).range(0.0..=10000.0).speed(0.5).max_decimals(2);
                         let response_repeat_count = styled_component(ui, |ui| { ui.add(text_edit_repeat_count) });
-                        ui.label(text(" times per "));
-                        let repeat_time_unit = ComboBox::new("repeat_time_unit", "").selected_text(self.repeat_count_time_unit.singular());
+                        ui.add_space(5.0);
+                        if !every {
+                            repeat_mode_ui(ui, &mut self.scenario.repeat_mode);
+                            ui.add_space(5.0);
+                        }
+                        let plural = every && self.scenario.repeat_count != 1.0;
+                        let unit_label = |unit: &TimeUnit| if plural { unit.plural(&self.scenario.conf_time_unit).to_string() } else { unit.singular(&self.scenario.conf_time_unit).to_string() };
+                        let repeat_time_unit = ComboBox::new("repeat_time_unit", "").selected_text(unit_label(&self.scenario.repeat_count_time_unit));
                         let response_repeat_count_unit = styled_component(ui, |ui| {
                             repeat_time_unit.show_ui(ui, |ui| {
-                                ui.selectable_value(&mut self.repeat_count_time_unit, TimeUnit::Hours, TimeUnit::Hours.singular()).changed()
-                                    || ui.selectable_value(&mut self.repeat_count_time_unit, TimeUnit::Days, TimeUnit::Days.singular()).changed()
-                                    || ui.selectable_value(&mut self.repeat_count_time_unit, TimeUnit::Weeks, TimeUnit::Weeks.singular()).changed()
-                                    || ui.selectable_value(&mut self.repeat_count_time_unit, TimeUnit::Months, TimeUnit::Months.singular()).changed()
+                                (every && ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Minutes, unit_label(&TimeUnit::Minutes)).changed())
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Hours, unit_label(&TimeUnit::Hours)).changed()
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Days, unit_label(&TimeUnit::Days)).changed()
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Weeks, unit_label(&TimeUnit::Weeks)).changed()
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Months, unit_label(&TimeUnit::Months)).changed()
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Quarters, unit_label(&TimeUnit::Quarters)).changed()
+                                    || ui.selectable_value(&mut self.scenario.repeat_count_time_unit, TimeUnit::Years, unit_label(&TimeUnit::Years)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.repeat_count_time_unit, &self.scenario.conf_time_unit, plural)
                             })
                         });
 
-                        ui.label(text(" this action."));
+                        ui.label(text(if every { "." } else { " this action." }));
 
                         (response_repeat_count, response_repeat_count_unit)
                     }).inner;
                     ui.add_space(5.0);
+                    self.frequency_growth_ui(ui);
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(text("We are "));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.people_count).range(1.0..=10000.0).speed(1.0)) });
+                        ui.label(text(" people doing it."));
+                        if self.scenario.people_count > 1 {
+                            ui.add_space(10.0);
+                            ui.checkbox(&mut self.scenario.invest_by_single_person, text("Only one of us invests"));
+                        }
+                    });
+                    ui.add_space(5.0);
                     let (_response_before_time, _response_before_time_unit) = ui.horizontal_wrapped(|ui| {
                         ui.label(text_with_color("It takes ", BEFORE_COLOR));
 
-                        let text_edit_before_time = DragValue::new(&mut self.before_taken_time).range(0.0..=10000.0).speed(1.0);
+                        let text_edit_before_time = DragValue::new(&mut self.scenario.before_taken_time).range(0.0..=10000.0).speed(1.0);
                         let response_before_time = styled_component(ui, |ui| { ui.add(text_edit_before_time) });
                         ui.add_space(5.0);
-                        let before_time_unit = ComboBox::new("before_time_unit", "").selected_text(self.before_taken_time_unit.plural());
+                        let before_time_unit = ComboBox::new("before_time_unit", "").selected_text(self.scenario.before_taken_time_unit.plural(&self.scenario.conf_time_unit));
                         let response_before_time_unit = styled_component(ui, |ui| {
                             before_time_unit.show_ui(ui, |ui| {
-                                ui.selectable_value(&mut self.before_taken_time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural()).changed()
-                                    || ui.selectable_value(&mut self.before_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural()).changed()
-                                    || ui.selectable_value(&mut self.before_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural()).changed()
-                                    || ui.selectable_value(&mut self.before_taken_time_unit, TimeUnit::Days, TimeUnit::Days.plural()).changed()
+                                ui.selectable_value(&mut self.scenario.before_taken_time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.before_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.before_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.before_taken_time_unit, TimeUnit::Days, TimeUnit::Days.plural(&self.scenario.conf_time_unit)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.before_taken_time_unit, &self.scenario.conf_time_unit, true)
                             })
                         });
                         ui.label(text(" to complete."));
                         (response_before_time, response_before_time_unit)
                     }).inner;
                     ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(text("Left as is, it gets "));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.before_degradation_percent_per_month).range(0.0..=100.0).speed(0.5)) });
+                        ui.label(text(" % slower every month."));
+                    });
+                    ui.add_space(5.0);
+                    failure_ui(ui, "before_failure", "It goes wrong ", &mut self.scenario.before_failure, &self.scenario.conf_time_unit);
+                    ui.add_space(5.0);
                     let (_response_after_time, _response_after_time_unit) = ui.horizontal_wrapped(|ui| {
                         ui.label(text_with_color("Optimizing/fixing", AFTER_COLOR));
                         ui.label(text(" the process would reduce this time to "));
 
-                        let text_edit_after_time = DragValue::new(&mut self.after_taken_time).range(0.0..=10000.0).speed(1.0);
+                        let text_edit_after_time = DragValue::new(&mut self.scenario.after_taken_time).range(0.0..=10000.0).speed(1.0);
                         let response_after_time = styled_component(ui, |ui| { ui.add(text_edit_after_time) });
                         ui.add_space(5.0);
-                        let repeat_time_unit = ComboBox::new("after_time_unit", "").selected_text(self.after_taken_time_unit.plural());
+                        let repeat_time_unit = ComboBox::new("after_time_unit", "").selected_text(self.scenario.after_taken_time_unit.plural(&self.scenario.conf_time_unit));
                         let response_after_time_unit = styled_component(ui, |ui| {
                             repeat_time_unit.show_ui(ui, |ui| {
-                                ui.selectable_value(&mut self.after_taken_time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural()).changed()
-                                    || ui.selectable_value(&mut self.after_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural()).changed()
-                                    || ui.selectable_value(&mut self.after_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural()).changed()
+                                ui.selectable_value(&mut self.scenario.after_taken_time_unit, TimeUnit::Seconds, TimeUnit::Seconds.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.after_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.after_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(&self.scenario.conf_time_unit)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.after_taken_time_unit, &self.scenario.conf_time_unit, true)
                             })
                         });
                         (response_after_time, response_after_time_unit)
                     }).inner;
                     ui.add_space(5.0);
+                    failure_ui(ui, "after_failure", "Optimized, it would go wrong ", &mut self.scenario.after_failure, &self.scenario.conf_time_unit);
+                    ui.add_space(5.0);
+                    self.adoption_ui(ui);
+                    ui.add_space(5.0);
+                    self.induced_demand_ui(ui);
+                    ui.add_space(5.0);
+                    self.multitasking_ui(ui);
+                    ui.add_space(5.0);
+                    self.refocus_ui(ui);
+                    ui.add_space(5.0);
                     let (_response_invest_time, _response_invest_time_unit) = ui.horizontal_wrapped(|ui| {
                         ui.label(text("For this I have to "));
                         ui.label(text_with_color("invest ", INVEST_COLOR));
-                        let response_invest_time = styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.invest_taken_time).range(0.0..=10000.0).speed(1.0)) });
+                        let response_invest_time = styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.invest_taken_time).range(0.0..=10000.0).speed(1.0)) });
                         ui.add_space(5.0);
-                        let repeat_time_unit = ComboBox::new("invest_time_unit", "").selected_text(self.invest_taken_time_unit.plural());
+                        let repeat_time_unit = ComboBox::new("invest_time_unit", "").selected_text(self.scenario.invest_taken_time_unit.plural(&self.scenario.conf_time_unit));
                         let response_invest_time_unit = crate::styled_component(ui, |ui| {
                             repeat_time_unit.show_ui(ui, |ui| {
-                                ui.selectable_value(&mut self.invest_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural()).changed()
-                                    || ui.selectable_value(&mut self.invest_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural()).changed()
-                                    || ui.selectable_value(&mut self.invest_taken_time_unit, TimeUnit::Days, TimeUnit::Days.plural()).changed()
+                                ui.selectable_value(&mut self.scenario.invest_taken_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.invest_taken_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.invest_taken_time_unit, TimeUnit::Days, TimeUnit::Days.plural(&self.scenario.conf_time_unit)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.invest_taken_time_unit, &self.scenario.conf_time_unit, true)
                             })
                         });
                         (response_invest_time, response_invest_time_unit)
                     }).inner;
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        let mut part_time = self.scenario.invest_hours_per_week.is_some();
+                        if ui.checkbox(&mut part_time, text("We can only put ")).changed() {
+                            self.scenario.invest_hours_per_week = if part_time { Some(4.0) } else { None };
+                        }
+                        if let Some(hours_per_week) = self.scenario.invest_hours_per_week.as_mut() {
+                            styled_component(ui, |ui| { ui.add(DragValue::new(hours_per_week).range(0.5..=168.0).speed(0.5)) });
+                            ui.label(text(&format!(" hours per week into it, it ships on day {:.1} and the process keeps running meanwhile.", self.scenario.ship_day())));
+                        } else {
+                            ui.label(text("a few hours per week into it."));
+                        }
+                    });
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(text("It works out with "));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.success_probability_percent).range(0.0..=100.0).speed(1.0)) });
+                        ui.label(text(" % chances, and the investment may take ×"));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.invest_overrun_factor).range(1.0..=10.0).speed(0.05)) });
+                        ui.label(text(" longer than estimated."));
+                    });
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(text("We can only start on it on day "));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.start_delay_days).range(0.0..=10000.0).speed(1.0)) });
+                        if let Some(date) = self.scenario.date_of_day(self.scenario.start_delay_days).filter(|_| self.scenario.start_delay_days > 0.0) {
+                            ui.label(text(&format!(", on {}", date.format_long())));
+                        }
+                        ui.label(text("."));
+                    });
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(text("Once done, maintaining it takes "));
+                        styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.maintenance_time).range(0.0..=10000.0).speed(0.5)) });
+                        ui.add_space(5.0);
+                        let maintenance_time_unit = ComboBox::new("maintenance_time_unit", "").selected_text(self.scenario.maintenance_time_unit.plural(&self.scenario.conf_time_unit));
+                        styled_component(ui, |ui| {
+                            maintenance_time_unit.show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.scenario.maintenance_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_time_unit, TimeUnit::Days, TimeUnit::Days.plural(&self.scenario.conf_time_unit)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.maintenance_time_unit, &self.scenario.conf_time_unit, true)
+                            })
+                        });
+                        ui.label(text(" per "));
+                        let maintenance_period_unit = ComboBox::new("maintenance_period_unit", "").selected_text(self.scenario.maintenance_period_unit.singular(&self.scenario.conf_time_unit));
+                        styled_component(ui, |ui| {
+                            maintenance_period_unit.show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.scenario.maintenance_period_unit, TimeUnit::Days, TimeUnit::Days.singular(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_period_unit, TimeUnit::Weeks, TimeUnit::Weeks.singular(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_period_unit, TimeUnit::Months, TimeUnit::Months.singular(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_period_unit, TimeUnit::Quarters, TimeUnit::Quarters.singular(&self.scenario.conf_time_unit)).changed()
+                                    || ui.selectable_value(&mut self.scenario.maintenance_period_unit, TimeUnit::Years, TimeUnit::Years.singular(&self.scenario.conf_time_unit)).changed()
+                                    || custom_time_units_selectable_values(ui, &mut self.scenario.maintenance_period_unit, &self.scenario.conf_time_unit, false)
+                            })
+                        });
+                        ui.label(text("."));
+                    });
+                    ui.add_space(5.0);
+                    ui.horizontal_wrapped(|ui| {
+                        let mut sunset = self.scenario.lifetime_days.is_some();
+                        if ui.checkbox(&mut sunset, text("This process stops existing after ")).changed() {
+                            self.scenario.lifetime_days = if sunset { Some(self.scenario.scale_number_of_day as f64 / 2.0) } else { None };
+                        }
+                        if let Some(lifetime_days) = self.scenario.lifetime_days.as_mut() {
+                            styled_component(ui, |ui| { ui.add(DragValue::new(lifetime_days).range(1.0..=10000.0).speed(1.0)) });
+                            ui.label(text(" days."));
+                        }
+                    });
                     ui.add_space(10.0);
                     ui.collapsing(text("⚙ Configuration"), |ui| {
                         ui.horizontal_wrapped(|ui| {
                             ui.label(text("I want to see a projection of next "));
-                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scale_number_of_day).range(1.0..=10000.0).speed(2.0)) });
+                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.scale_number_of_day).range(1.0..=10000.0).speed(2.0)) });
                             ui.label(text(" days"));
                         });
+                        ui.add_space(5.0);
                         ui.horizontal_wrapped(|ui| {
-                            ui.label(text("1 day is "));
-                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.conf_time_unit.number_of_hours_per_day).range(1.0..=24.0).speed(1.0)) });
-                            ui.label(text(" hours"));
+                            let mut dated = self.scenario.start_date.is_some();
+                            if ui.checkbox(&mut dated, text("The project starts on ")).changed() {
+                                self.scenario.start_date = if dated { Some(today()) } else { None };
+                            }
+                            if let Some(start_date) = self.scenario.start_date.as_mut() {
+                                styled_component(ui, |ui| { ui.add(DragValue::new(&mut start_date.day).range(1.0..=31.0).speed(1.0)) });
+                                ui.label(text("/"));
+                                styled_component(ui, |ui| { ui.add(DragValue::new(&mut start_date.month).range(1.0..=12.0).speed(1.0)) });
+                                ui.label(text("/"));
+                                styled_component(ui, |ui| { ui.add(DragValue::new(&mut start_date.year).range(1970.0..=2100.0).speed(1.0)) });
+                                // normalize days past the end of the month, e.g. 31/02
+                                *start_date = Date::from_days(start_date.to_days());
+                            }
                         });
                         ui.add_space(5.0);
+                        self.calendar_ui(ui);
+                        let mut removed_custom_unit = None;
+                        // custom units are only defined from built-in ones, which do not need the configuration to be labelled
+                        let built_in_conf = &self.built_in_conf;
+                        for (index, custom_unit) in self.scenario.conf_time_unit.custom_units.iter_mut().enumerate() {
+                            ui.add_space(5.0);
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(text("1 "));
+                                styled_component(ui, |ui| { ui.add(egui::TextEdit::singleline(&mut custom_unit.singular).desired_width(80.0)) });
+                                ui.label(text(" ("));
+                                styled_component(ui, |ui| { ui.add(egui::TextEdit::singleline(&mut custom_unit.plural).desired_width(80.0)) });
+                                ui.label(text(") is "));
+                                styled_component(ui, |ui| { ui.add(DragValue::new(&mut custom_unit.value).range(0.01..=10000.0).speed(1.0)) });
+                                ui.add_space(5.0);
+                                let custom_unit_base = ComboBox::new(("custom_unit_base", index), "").selected_text(custom_unit.unit.plural(built_in_conf).to_string());
+                                styled_component(ui, |ui| {
+                                    custom_unit_base.show_ui(ui, |ui| {
+                                        for unit in TimeUnit::BUILT_IN {
+                                            let label = unit.plural(built_in_conf).to_string();
+                                            ui.selectable_value(&mut custom_unit.unit, unit, label);
+                                        }
+                                    })
+                                });
+                                ui.add_space(5.0);
+                                if ui.button(text("🗑")).clicked() {
+                                    removed_custom_unit = Some(index);
+                                }
+                            });
+                        }
+                        if let Some(index) = removed_custom_unit {
+                            self.scenario.remove_custom_unit(index);
+                        }
+                        ui.add_space(5.0);
+                        if ui.button(text("➕ Add a time unit")).clicked() {
+                            self.scenario.conf_time_unit.custom_units.push(CustomTimeUnit {
+                                singular: "cycle".to_string(),
+                                plural: "cycles".to_string(),
+                                value: 1.0,
+                                unit: TimeUnit::Weeks,
+                            });
+                        }
+                        ui.add_space(5.0);
                         ui.horizontal_wrapped(|ui| {
-                            ui.label(text("1 week is "));
-                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.conf_time_unit.number_of_day_per_week).range(1.0..=7.0).speed(1.0)) });
-                            ui.label(text(" days"));
+                            ui.label(text("1 hour of work costs "));
+                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.cost_model.hourly_rate).range(0.0..=10000.0).speed(1.0)) });
+                            ui.add_space(5.0);
+                            styled_component(ui, |ui| { ui.add(egui::TextEdit::singleline(&mut self.scenario.cost_model.currency).desired_width(30.0)) });
+                            ui.label(text(" (0 to not show costs) with an overhead of ×"));
+                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.scenario.cost_model.overhead_multiplier).range(1.0..=10.0).speed(0.05)) });
                         });
                         ui.add_space(5.0);
                         ui.horizontal_wrapped(|ui| {
-                            ui.label(text("1 month is "));
-                            styled_component(ui, |ui| { ui.add(DragValue::new(&mut self.conf_time_unit.number_of_day_per_month).range(1.0..=31.0).speed(1.0)) });
-                            ui.label(text(" days"));
+                            let mut discounted = self.scenario.discount_rate_percent_per_year.is_some();
+                            if ui.checkbox(&mut discounted, text("Time later is worth less than time now, discount it by ")).changed() {
+                                self.scenario.discount_rate_percent_per_year = if discounted { Some(5.0) } else { None };
+                            }
+                            if let Some(percent) = self.scenario.discount_rate_percent_per_year.as_mut() {
+                                styled_component(ui, |ui| { ui.add(DragValue::new(percent).range(0.0..=100.0).speed(0.5)) });
+                                ui.label(text(" % per year."));
+                            }
                         });
-                    })
-                });
-            let intersection = self.intersection(invest_time_in_hours, after_invest_time);
-            egui::TopBottomPanel::bottom("bottom").show_inside(ui, |ui| {
-                if intersection.1 <= 0.0 || intersection.0 <= 0.0 || (self.after_taken_time == self.before_taken_time && self.after_taken_time_unit == self.before_taken_time_unit) {
-                    ui.add(Label::new(RichText::heading(RichText::new("It looks like your optimisation will not be worth it, are you sure about data you enter?"))));
-                } else if (self.scale_number_of_day as f64) < intersection.0 {
-                    ui.horizontal_wrapped(|ui| {
-                        ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
-                        ui.heading("After ");
-                        ui.heading(RichText::new(format!("{} ", self.scale_number_of_day)).strong());
-                        ui.heading(RichText::new("days ").strong());
-                        ui.heading("you would not save time. ");
-                        ui.heading("You will only start to save time after ");
-                        let x = TimeUnit::Days.to_hours(intersection.0, &self.conf_time_unit);
-                        let roi = Self::value_to_human_duration(x, false, &self.conf_time_unit);
-                        ui.heading(RichText::new(roi).strong());
                     });
-                    ui.horizontal_wrapped(|ui| {
-                        ui.heading("Increase projection time frame in configuration (⚙)")
-                    });
-                } else {
-                    let x = TimeUnit::Days.to_hours(intersection.0, &self.conf_time_unit);
-                    let roi = Self::value_to_human_duration(x, false, &self.conf_time_unit);
-                    let before = self.time_taken_per_day_in_hours(self.scale_number_of_day as f64, &self.before_taken_time_unit, self.before_taken_time).1;
-                    let after = self.time_taken_per_day_in_hours(self.scale_number_of_day as f64, &self.after_taken_time_unit, self.after_taken_time).1;
-                    let saved_hours = Self::value_to_human_duration(before - after, false, &self.conf_time_unit);
-                    ui.horizontal_wrapped(|ui| {
-                        ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
-                        ui.heading("After ");
-                        ui.heading(RichText::new(format!("{} ", self.scale_number_of_day)).strong());
-                        ui.heading(RichText::new("days ").strong());
-                        ui.heading("you would have saved ");
-                        ui.heading(RichText::new(format!("{}. ", saved_hours)).strong());
-                        ui.heading("You will start to save time after ");
-                        ui.heading(RichText::new(roi).strong());
+                    ui.collapsing(text("🎲 Uncertainty"), |ui| {
+                        self.uncertainty_ui(ui);
                     });
-                    ui.horizontal_wrapped(|ui| {
-                        ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
-                        ui.heading("Too busy to improve? Congratulation, after ");
-                        ui.heading(RichText::new(format!("{} ", self.scale_number_of_day)).strong());
-                        ui.heading(RichText::new("days ").strong());
-                        ui.heading("you would have wasted ");
-                        ui.heading(RichText::new(format!("{}. ", saved_hours)).strong());
+                    ui.collapsing(text("🧮 Show your work"), |ui| {
+                        let steps = breakdown(&self.scenario);
+                        egui::Grid::new("breakdown").spacing(Vec2 { x: 10.0, y: 4.0 }).striped(true).show(ui, |ui| {
+                            for step in steps.iter() {
+                                ui.label(text(&step.label));
+                                ui.label(text(&step.formula));
+                                ui.end_row();
+                            }
+                        });
+                        ui.add_space(5.0);
+                        if ui.button(text("📋 Copy as text")).clicked() {
+                            ui.ctx().copy_text(to_plain_text(&steps));
+                        }
                     });
+                });
+            let evaluation = self.scenario.evaluate();
+            let starting_now = (self.scenario.start_delay_days > 0.0).then(|| self.scenario.starting_now().evaluate());
+            let risk_adjusted = self.scenario.risk_adjusted().map(|scenario| scenario.evaluate());
+            let wall_clock = self.scenario.wall_clock().map(|scenario| scenario.evaluate());
+            self.refresh_simulation(ctx);
+            egui::TopBottomPanel::bottom("bottom").show_inside(ui, |ui| {
+                match evaluation.break_even {
+                    BreakEven::NeverAfterNotFaster | BreakEven::CapacitySaturated | BreakEven::NeverRecovered => {
+                        ui.add(Label::new(RichText::heading(RichText::new("It looks like your optimisation will not be worth it, are you sure about data you enter?"))));
+                        self.diagnostics_ui(ui);
+                    }
+                    BreakEven::NeverBeforeSunset { lifetime_days, .. } => {
+                        ui.horizontal_wrapped(|ui| {
+                            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+                            ui.heading("This process stops existing after ");
+                            ui.heading(RichText::new(format!("{} days", lifetime_days)).strong());
+                            ui.heading(", before the investment is recovered: it is not worth it.");
+                        });
+                        self.diagnostics_ui(ui);
+                    }
+                    BreakEven::NeverWithinHorizon { day, .. } => {
+                        ui.horizontal_wrapped(|ui| {
+                            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+                            ui.heading("After ");
+                            ui.heading(RichText::new(format!("{} ", self.scenario.scale_number_of_day)).strong());
+                            ui.heading(RichText::new("days ").strong());
+                            ui.heading(format!("{} would not save time. ", self.subject()));
+                            ui.heading("You will only start to save time after ");
+                            let x = TimeUnit::Days.to_hours(day, &self.scenario.conf_time_unit);
+                            let roi = value_to_human_duration(x, false, &self.scenario.conf_time_unit);
+                            ui.heading(RichText::new(roi).strong());
+                            self.break_even_date_ui(ui, day);
+                        });
+                        ui.horizontal_wrapped(|ui| {
+                            ui.heading("Increase projection time frame in configuration (⚙)")
+                        });
+                        self.money_ui(ui, &evaluation);
+                        self.diagnostics_ui(ui);
+                    }
+                    BreakEven::PaysOffOnDay { day, .. } | BreakEven::PaysOffDuringInvestment { day, .. } => {
+                        let x = TimeUnit::Days.to_hours(day, &self.scenario.conf_time_unit);
+                        let roi = value_to_human_duration(x, false, &self.scenario.conf_time_unit);
+                        let saved_hours = value_to_human_duration(evaluation.saved_hours, false, &self.scenario.conf_time_unit);
+                        ui.horizontal_wrapped(|ui| {
+                            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+                            ui.heading("After ");
+                            ui.heading(RichText::new(format!("{} ", self.scenario.scale_number_of_day)).strong());
+                            ui.heading(RichText::new("days ").strong());
+                            ui.heading(format!("{} would have saved ", self.subject()));
+                            ui.heading(RichText::new(format!("{}. ", saved_hours)).strong());
+                            ui.heading("You will start to save time after ");
+                            ui.heading(RichText::new(roi).strong());
+                            self.break_even_date_ui(ui, day);
+                        });
+                        ui.horizontal_wrapped(|ui| {
+                            ui.style_mut().spacing.item_spacing = Vec2 { x: 0.0, y: 0.0 };
+                            ui.heading("Too busy to improve? Congratulation, after ");
+                            ui.heading(RichText::new(format!("{} ", self.scenario.scale_number_of_day)).strong());
+                            ui.heading(RichText::new("days ").strong());
+                            ui.heading(format!("{} would have wasted ", self.subject()));
+                            ui.heading(RichText::new(format!("{}. ", saved_hours)).strong());
+                        });
+                        if let Some(lifetime_days) = self.scenario.lifetime_days {
+                            ui.label(text(&format!("The investment is recovered before the process stops existing after {} days.", lifetime_days)));
+                        }
+                        self.money_ui(ui, &evaluation);
+                    }
+                }
+                if let Some(risk_adjusted) = risk_adjusted.as_ref() {
+                    self.risk_adjusted_ui(ui, &evaluation, risk_adjusted);
+                }
+                if self.scenario.induced_demand != InducedDemand::Unchanged {
+                    let extra_executions = self.scenario.extra_executions_at(self.scenario.scale_number_of_day as f64);
+                    ui.label(text(&format!("Repeated ×{:.2} as often once optimized, it runs {:.0} more times over the {} days.",
+                                           self.scenario.induced_demand_factor(), extra_executions, self.scenario.scale_number_of_day)));
+                }
+                if let Some(refocus_hours) = evaluation.refocus_saved_hours {
+                    ui.label(text(&format!("Getting back to focus after each execution accounts for {} of the saved time.",
+                                           value_to_human_duration(refocus_hours, false, &self.scenario.conf_time_unit))));
                 }
+                if let Some(wall_clock) = wall_clock.as_ref() {
+                    let conf = &self.scenario.conf_time_unit;
+                    let break_even = match wall_clock.break_even.point() {
+                        Some((day, _)) => format!("breaks even on {}", self.day_label(day)),
+                        None => "never breaks even".to_string(),
+                    };
+                    ui.label(text(&format!("Counting waiting as hands-on time, it {} and saves {} rather than {}.", break_even,
+                                           value_to_human_duration(wall_clock.saved_hours, false, conf), value_to_human_duration(evaluation.saved_hours, false, conf))));
+                }
+                self.discounted_ui(ui, &evaluation);
+                if self.scenario.start_delay_days > 0.0 {
+                    let waiting_cost = self.scenario.waiting_cost_at(self.scenario.scale_number_of_day as f64);
+                    let cost_model = &self.scenario.cost_model;
+                    let money = if cost_model.enabled() { format!(" ({})", cost_model.format(cost_model.cost(waiting_cost))) } else { String::new() };
+                    ui.label(text(&format!("Starting on day {} rather than now costs {}{} over the {} days.", self.scenario.start_delay_days,
+                                           value_to_human_duration(waiting_cost, false, &self.scenario.conf_time_unit), money, self.scenario.scale_number_of_day)));
+                }
+                self.simulation_ui(ui);
+                self.sensitivity_ui(ui);
             });
             let label_fmt = |_s: &str, val: &PlotPoint| {
                 if val.y < 0.0 || val.x < 0.0 {
                     return String::new();
                 }
-                let label = Self::value_to_human_duration(val.y, true, &self.conf_time_unit);
-                format!("Day: {}\nSpent time: {}", val.x.trunc(), label)
+                let label = value_to_human_duration(val.y, true, &self.scenario.conf_time_unit);
+                let day = match self.scenario.date_of_day(val.x) {
+                    Some(date) => format!("Date: {}", date.format_long()),
+                    None => format!("Day: {}", val.x.trunc()),
+                };
+                let cost_model = &self.scenario.cost_model;
+                if cost_model.enabled() {
+                    return format!("{}\nSpent time: {}\nCost: {}", day, label, cost_model.format(cost_model.cost(val.y)));
+                }
+                format!("{}\nSpent time: {}", day, label)
             };
             egui::CentralPanel::default().show_inside(ui, |ui| {
                 let id = Id::new("plot");
+                let mut y_axes = vec![AxisHints::new_y()];
+                let cost_model = &self.scenario.cost_model;
+                if cost_model.enabled() {
+                    y_axes.push(AxisHints::new_y().label("Cost").placement(HPlacement::Right).formatter(|grid_mark, _range| {
+                        if grid_mark.value <= 0.0 {
+                            return String::new();
+                        }
+                        format!("{}{:.0}", cost_model.currency, cost_model.cost(grid_mark.value))
+                    }));
+                }
+                let x_axis = match self.scenario.start_date {
+                    Some(_) => AxisHints::new_x().label("Date").formatter(|grid_mark, _range| {
+                        match self.scenario.date_of_day(grid_mark.value) {
+                            Some(date) if grid_mark.value >= 0.0 => date.format_short(),
+                            _ => String::new(),
+                        }
+                    }),
+                    None => AxisHints::new_y().label("Day"),
+                };
                 let plot = Plot::new("plot").id(id)
-                    .custom_x_axes(vec![AxisHints::new_y().label("Day")])
+                    .custom_x_axes(vec![x_axis])
+                    .custom_y_axes(y_axes)
                     .label_formatter(label_fmt)
                     .y_axis_formatter(|grid_mark, _range| {
                         if grid_mark.value <= 0.0 {
                             return String::new();
                         }
                         let label = if grid_mark.value < 0.016 {
-                            format!("{}s", TimeUnit::Hours.to_seconds(grid_mark.value))
+                            format!("{}s", TimeUnit::Hours.to_seconds(grid_mark.value, &self.scenario.conf_time_unit))
                         } else if grid_mark.value < 1.0 {
-                            Self::label_hours_to_minutes(grid_mark.value, true)
+                            label_hours_to_minutes(grid_mark.value, true, &self.scenario.conf_time_unit)
                         } else {
-                            Self::label_hours_to_hours_minutes(grid_mark.value, true)
+                            label_hours_to_hours_minutes(grid_mark.value, true, &self.scenario.conf_time_unit)
                         };
-                        format!("{}", label)
+                        label
                     })
                     .legend(Legend::default().position(Corner::LeftTop))
                     .show_axes(true)
@@ -471,11 +1041,38 @@ impl App for MyApp {
                     ;
 
                 let _response = plot.show(ui, |plot_ui| {
-                    plot_ui.line(self.before_line());
-                    plot_ui.line(self.invest_time_line(invest_time_in_hours, after_invest_time));
-                    plot_ui.line(self.after_line(invest_time_in_hours, after_invest_time));
-                    if intersection.1 > 0.0 && intersection.0 > 0.0 {
-                        plot_ui.points(Points::new(PlotPoints::from([intersection.0, intersection.1])).color(Color32::RED).radius(2.0))
+                    if let Some((_, simulation)) = self.simulation.as_ref() {
+                        Self::confidence_band(plot_ui, &simulation.before_band, BEFORE_COLOR);
+                        Self::confidence_band(plot_ui, &simulation.after_band, AFTER_COLOR);
+                    }
+                    if let Some(wall_clock) = wall_clock.as_ref() {
+                        plot_ui.line(Self::before_line(wall_clock).color(BEFORE_COLOR.gamma_multiply(0.5)).style(LineStyle::dashed_dense()).name("before, wall-clock"));
+                        plot_ui.line(Self::variant_line(wall_clock, LineStyle::dashed_dense(), "after, wall-clock"));
+                    }
+                    match wall_clock {
+                        Some(_) => plot_ui.line(Self::before_line(&evaluation).name("before, effective cost")),
+                        None => plot_ui.line(Self::before_line(&evaluation)),
+                    }
+                    plot_ui.line(Self::invest_time_line(&evaluation));
+                    match risk_adjusted.as_ref() {
+                        Some(risk_adjusted) => {
+                            plot_ui.line(Self::after_line(&evaluation).name("after, optimistic"));
+                            plot_ui.line(Self::variant_line(risk_adjusted, LineStyle::dotted_dense(), "after, risk-adjusted"));
+                        }
+                        None if wall_clock.is_some() => plot_ui.line(Self::after_line(&evaluation).name("after, effective cost")),
+                        None => plot_ui.line(Self::after_line(&evaluation)),
+                    }
+                    if let Some(starting_now) = starting_now.as_ref() {
+                        plot_ui.line(Self::variant_line(starting_now, LineStyle::dashed_loose(), "after, starting now"));
+                    }
+                    if let Some(lifetime_days) = self.scenario.lifetime_days {
+                        plot_ui.vline(VLine::new(lifetime_days).color(Color32::GRAY).style(LineStyle::dashed_dense()).name("process stops"));
+                    }
+                    if let Some((day, hours)) = evaluation.break_even.point() {
+                        plot_ui.points(Points::new(PlotPoints::from([day, hours])).color(Color32::RED).radius(2.0))
+                    }
+                    if let Some((day, hours)) = risk_adjusted.as_ref().and_then(|risk_adjusted| risk_adjusted.break_even.point()) {
+                        plot_ui.points(Points::new(PlotPoints::from([day, hours])).color(Color32::RED.gamma_multiply(0.5)).radius(2.0))
                     }
                 });
                 let mut plot_memory = PlotMemory::load(ctx, id);
@@ -483,13 +1080,13 @@ impl App for MyApp {
                 let mut transform = plot_memory.transform();
                 let mut changed = false;
                 if plot_memory.bounds().min()[0] < 0.0 {
-                    let mut bounds = plot_memory.bounds().clone();
+                    let mut bounds = *plot_memory.bounds();
                     bounds.translate((plot_memory.bounds().min()[0].abs(), 0.0));
                     transform.set_bounds(bounds);
                     changed = true;
                 }
                 if plot_memory.bounds().min()[1] < 0.0 {
-                    let mut bounds = plot_memory.bounds().clone();
+                    let mut bounds = *plot_memory.bounds();
                     bounds.translate((0.0, plot_memory.bounds().min()[1].abs()));
                     transform.set_bounds(bounds);
                     changed = true;
@@ -503,6 +1100,75 @@ impl App for MyApp {
         });
     }
 }
+/// One row per input, the most influential on top, bars going from the base value to the value with the input decreased and increased.
+fn tornado_plot(ui: &mut Ui, id: &str, ranked: Vec<&Sensitivity>, base: &Outcome, variation: f64, value: fn(&Outcome) -> Option<f64>) {
+    let Some(base_value) = value(base) else {
+        ui.label(text("Never pays off, there is nothing to compare with."));
+        return;
+    };
+    let bars = |outcome: fn(&Sensitivity) -> &Outcome| -> Vec<Bar> {
+        ranked.iter().enumerate()
+            .filter_map(|(i, sensitivity)| value(outcome(sensitivity)).map(|value| (i, sensitivity, value)))
+            .map(|(i, sensitivity, value)| Bar::new(i as f64, value - base_value).base_offset(base_value).width(0.6).name(sensitivity.input))
+            .collect()
+    };
+    let names: Vec<&str> = ranked.iter().map(|sensitivity| sensitivity.input).collect();
+    Plot::new(id).height(200.0).allow_drag(false).allow_zoom(false).allow_scroll(false)
+        .legend(Legend::default().position(Corner::RightBottom))
+        .y_grid_spacer(uniform_grid_spacer(|_| [1.0, 5.0, 10.0]))
+        .y_axis_formatter(move |grid_mark, _range| {
+            let index = grid_mark.value.round();
+            if (grid_mark.value - index).abs() > f64::EPSILON || index < 0.0 {
+                return String::new();
+            }
+            names.get(index as usize).map(|name| name.to_string()).unwrap_or_default()
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(bars(|sensitivity| &sensitivity.low)).horizontal().color(BEFORE_COLOR).name(format!("-{}%", variation)));
+            plot_ui.bar_chart(BarChart::new(bars(|sensitivity| &sensitivity.high)).horizontal().color(AFTER_COLOR).name(format!("+{}%", variation)));
+        });
+}
+
+fn estimate_ui(ui: &mut Ui, label: &str, estimate: &mut Estimate, likely: f64, unit: &str) {
+    ui.add_space(5.0);
+    ui.horizontal_wrapped(|ui| {
+        ui.label(text(label));
+        styled_component(ui, |ui| { ui.add(DragValue::new(&mut estimate.min).range(0.0..=likely).speed(1.0)) });
+        ui.label(text(" and "));
+        styled_component(ui, |ui| { ui.add(DragValue::new(&mut estimate.max).range(likely..=f64::MAX).speed(1.0)) });
+        ui.label(text(&format!(" {}, most likely {}", unit, likely)));
+    });
+}
+
+fn failure_ui(ui: &mut Ui, id: &str, label: &str, failure: &mut Failure, conf: &ConfTimeUnit) {
+    ui.horizontal_wrapped(|ui| {
+        ui.label(text(label));
+        styled_component(ui, |ui| { ui.add(DragValue::new(&mut failure.rate_percent).range(0.0..=100.0).speed(0.1)) });
+        ui.label(text(" % of the time, recovering takes "));
+        styled_component(ui, |ui| { ui.add(DragValue::new(&mut failure.recovery_time).range(0.0..=10000.0).speed(0.5)) });
+        ui.add_space(5.0);
+        let recovery_time_unit = ComboBox::new(id, "").selected_text(failure.recovery_time_unit.plural(conf));
+        styled_component(ui, |ui| {
+            recovery_time_unit.show_ui(ui, |ui| {
+                ui.selectable_value(&mut failure.recovery_time_unit, TimeUnit::Minutes, TimeUnit::Minutes.plural(conf)).changed()
+                    || ui.selectable_value(&mut failure.recovery_time_unit, TimeUnit::Hours, TimeUnit::Hours.plural(conf)).changed()
+                    || ui.selectable_value(&mut failure.recovery_time_unit, TimeUnit::Days, TimeUnit::Days.plural(conf)).changed()
+                    || custom_time_units_selectable_values(ui, &mut failure.recovery_time_unit, conf, true)
+            })
+        });
+        ui.label(text("."));
+    });
+}
+
+fn custom_time_units_selectable_values(ui: &mut Ui, current_value: &mut TimeUnit, conf_time_unit: &ConfTimeUnit, plural: bool) -> bool {
+    let mut changed = false;
+    for (index, custom_unit) in conf_time_unit.custom_units.iter().enumerate() {
+        let label = if plural { custom_unit.plural.as_str() } else { custom_unit.singular.as_str() };
+        changed |= ui.selectable_value(current_value, TimeUnit::Custom(index), label).changed();
+    }
+    changed
+}
+
 fn styled_component<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
     ui.scope(|ui| {
         let expansion = 0.0;
@@ -536,68 +1202,3 @@ fn text_with_color(text: &str, color: Color32) -> RichText {
     RichText::new(text).size(14.0).color(color).line_height(Some(18.0))
 }
 
-
-#[cfg(test)]
-mod tests {
-    use crate::{ConfTimeUnit, MyApp, TimeUnit};
-
-    #[test]
-    fn conversion_to_hours() {
-        let conf_time_unit = ConfTimeUnit {
-            number_of_hours_per_day: 8,
-            number_of_day_per_week: 5,
-            number_of_day_per_month: 22,
-        };
-        assert_eq!(TimeUnit::Seconds.to_hours(2.0, &conf_time_unit), 0.0005555555555555556);
-        assert_eq!(TimeUnit::Minutes.to_hours(2.0, &conf_time_unit), 0.03333333333333333);
-        assert_eq!(TimeUnit::Hours.to_hours(2.0, &conf_time_unit), 2.0);
-        assert_eq!(TimeUnit::Days.to_hours(2.0, &conf_time_unit), 16.0);
-        assert_eq!(TimeUnit::Weeks.to_hours(2.0, &conf_time_unit), 80.0);
-        assert_eq!(TimeUnit::Months.to_hours(2.0, &conf_time_unit), 352.0);
-        assert_eq!(TimeUnit::Years.to_hours(2.0, &conf_time_unit), 4224.0);
-    }
-
-    #[test]
-    fn conversion_time_per_day() {
-        let conf_time_unit = ConfTimeUnit {
-            number_of_hours_per_day: 8,
-            number_of_day_per_week: 5,
-            number_of_day_per_month: 30,
-        };
-        assert_eq!(TimeUnit::Seconds.to_times_per_days(10.0, &conf_time_unit), 288000.0);
-        assert_eq!(TimeUnit::Minutes.to_times_per_days(10.0, &conf_time_unit), 4800.0);
-        assert_eq!(TimeUnit::Hours.to_times_per_days(10.0, &conf_time_unit), 80.0);
-        assert_eq!(TimeUnit::Days.to_times_per_days(10.0, &conf_time_unit), 10.0);
-        assert_eq!(TimeUnit::Weeks.to_times_per_days(10.0, &conf_time_unit), 2.0);
-        assert_eq!(TimeUnit::Months.to_times_per_days(10.0, &conf_time_unit), 0.3333333333333333);
-        assert_eq!(TimeUnit::Years.to_times_per_days(10.0, &conf_time_unit), 0.027777777777777776);
-    }
-
-    #[test]
-    fn time_taken() {
-        let mut app = MyApp::new();
-        // I repeat this action 10 times per day and it takes me 40 seconds each time
-        app.repeat_count = 10;
-        app.repeat_count_time_unit = TimeUnit::Days;
-        app.before_taken_time = 40.0;
-        app.before_taken_time_unit = TimeUnit::Seconds;
-        assert_eq!(app.time_taken_per_day_in_hours(1.0, &app.before_taken_time_unit, app.before_taken_time).1.round(),
-                   TimeUnit::Seconds.to_hours(40.0 * 10.0 /* 10 time per day */, &app.conf_time_unit).round());
-
-        // I repeat this action 10 times per hour and it takes me 2 minutes each time
-        app.repeat_count = 10;
-        app.repeat_count_time_unit = TimeUnit::Hours;
-        app.before_taken_time = 2.0;
-        app.before_taken_time_unit = TimeUnit::Minutes;
-        assert_eq!(app.time_taken_per_day_in_hours(1.0, &app.before_taken_time_unit, app.before_taken_time).1.round(),
-                   TimeUnit::Minutes.to_hours(2.0 * 80.0 /*80 times per day (8 h * 10 time)*/, &app.conf_time_unit).round());
-
-        // I repeat this action 2 times per week and it takes me 4 hours each time
-        app.repeat_count = 2;
-        app.repeat_count_time_unit = TimeUnit::Weeks;
-        app.before_taken_time = 4.0;
-        app.before_taken_time_unit = TimeUnit::Hours;
-        assert_eq!(app.time_taken_per_day_in_hours(1.0, &app.before_taken_time_unit, app.before_taken_time).1.round(),
-                   TimeUnit::Hours.to_hours(4.0 * 0.4 /* 0.4 times per day (2 time / 5 day)*/, &app.conf_time_unit).round());
-    }
-}
\ No newline at end of file
@@ -0,0 +1,1755 @@
+//! Headless ROI model: nothing in this module depends on egui/eframe, so it can be reused from tools and tests.
+
+use crate::calendar::{Calendar, Date};
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum TimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Quarters,
+    Years,
+    /// Index in `ConfTimeUnit::custom_units`.
+    Custom(usize),
+}
+
+impl TimeUnit {
+    pub const BUILT_IN: [TimeUnit; 8] = [TimeUnit::Seconds, TimeUnit::Minutes, TimeUnit::Hours, TimeUnit::Days,
+        TimeUnit::Weeks, TimeUnit::Months, TimeUnit::Quarters, TimeUnit::Years];
+
+    pub fn plural<'a>(&'a self, conf_time_unit: &'a ConfTimeUnit) -> &'a str {
+        match self {
+            TimeUnit::Seconds => "seconds",
+            TimeUnit::Minutes => "minutes",
+            TimeUnit::Hours => "hours",
+            TimeUnit::Days => "days",
+            TimeUnit::Weeks => "weeks",
+            TimeUnit::Months => "months",
+            TimeUnit::Quarters => "quarters",
+            TimeUnit::Years => "years",
+            TimeUnit::Custom(index) => &conf_time_unit.custom_units[*index].plural,
+        }
+    }
+    pub fn singular<'a>(&'a self, conf_time_unit: &'a ConfTimeUnit) -> &'a str {
+        match self {
+            TimeUnit::Seconds => "second",
+            TimeUnit::Minutes => "minute",
+            TimeUnit::Hours => "hour",
+            TimeUnit::Days => "day",
+            TimeUnit::Weeks => "week",
+            TimeUnit::Months => "month",
+            TimeUnit::Quarters => "quarter",
+            TimeUnit::Years => "year",
+            TimeUnit::Custom(index) => &conf_time_unit.custom_units[*index].singular,
+        }
+    }
+    /// Worked seconds, consistent with [`TimeUnit::to_hours`].
+    pub fn to_seconds(&self, value: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
+        self.to_hours(value, conf_time_unit) * 60.0 * 60.0
+    }
+
+    pub fn to_hours(&self, value: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
+        match self {
+            TimeUnit::Seconds => value / 60.0 / 60.0,
+            TimeUnit::Minutes => value / 60.0,
+            TimeUnit::Hours => value,
+            TimeUnit::Days => value * conf_time_unit.number_of_hours_per_day(),
+            TimeUnit::Weeks => value * conf_time_unit.number_of_hours_per_day() * conf_time_unit.number_of_day_per_week(),
+            TimeUnit::Months => value * conf_time_unit.number_of_hours_per_day() * conf_time_unit.number_of_day_per_month(),
+            TimeUnit::Quarters => value * conf_time_unit.number_of_hours_per_day() * conf_time_unit.number_of_day_per_month() * 3.0,
+            TimeUnit::Years => value * conf_time_unit.number_of_hours_per_day() * conf_time_unit.number_of_day_per_year(),
+            TimeUnit::Custom(index) => {
+                let custom_unit = &conf_time_unit.custom_units[*index];
+                custom_unit.unit.to_hours(value * custom_unit.value, conf_time_unit)
+            }
+        }
+    }
+
+    pub fn to_times_per_days(&self, value: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
+        match self {
+            TimeUnit::Seconds => value * 60.0 * 60.0 * conf_time_unit.number_of_hours_per_day(),
+            TimeUnit::Minutes => value * 60.0 * conf_time_unit.number_of_hours_per_day(),
+            TimeUnit::Hours => value * conf_time_unit.number_of_hours_per_day(),
+            TimeUnit::Days => value,
+            TimeUnit::Weeks => per_worked_days(value, conf_time_unit.number_of_day_per_week()),
+            TimeUnit::Months => per_worked_days(value, conf_time_unit.number_of_day_per_month()),
+            TimeUnit::Quarters => per_worked_days(value, conf_time_unit.number_of_day_per_month() * 3.0),
+            TimeUnit::Years => per_worked_days(value, conf_time_unit.number_of_day_per_year()),
+            TimeUnit::Custom(index) => {
+                let custom_unit = &conf_time_unit.custom_units[*index];
+                if custom_unit.value <= 0.0 {
+                    return 0.0;
+                }
+                custom_unit.unit.to_times_per_days(value / custom_unit.value, conf_time_unit)
+            }
+        }
+    }
+}
+
+/// A period without any worked day never comes around, so nothing happens in it.
+fn per_worked_days(value: f64, days: f64) -> f64 {
+    if days <= 0.0 {
+        return 0.0;
+    }
+    value / days
+}
+
+/// A unit defined by the user from a built-in one, e.g. "1 sprint is 10 days".
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomTimeUnit {
+    pub singular: String,
+    pub plural: String,
+    pub value: f64,
+    /// Always a built-in unit.
+    pub unit: TimeUnit,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfTimeUnit {
+    pub calendar: Calendar,
+    pub custom_units: Vec<CustomTimeUnit>,
+}
+
+impl ConfTimeUnit {
+    pub fn number_of_hours_per_day(&self) -> f64 {
+        self.calendar.number_of_hours_per_day()
+    }
+
+    pub fn number_of_day_per_week(&self) -> f64 {
+        self.calendar.number_of_day_per_week()
+    }
+
+    pub fn number_of_day_per_month(&self) -> f64 {
+        self.calendar.number_of_day_per_month()
+    }
+
+    pub fn number_of_day_per_year(&self) -> f64 {
+        self.calendar.number_of_day_per_year()
+    }
+}
+
+impl Default for ConfTimeUnit {
+    fn default() -> Self {
+        Self {
+            calendar: Calendar::default(),
+            custom_units: vec![
+                CustomTimeUnit { singular: "sprint".to_string(), plural: "sprints".to_string(), value: 10.0, unit: TimeUnit::Days },
+            ],
+        }
+    }
+}
+
+/// The break-even is searched up to this many times the projection, then extrapolated.
+const BREAK_EVEN_SEARCH_FACTOR: f64 = 10.0;
+const BISECTION_ITERATIONS: usize = 60;
+
+/// How `repeat_count` reads: "20 times per hour" or "once every 2 weeks".
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum RepeatMode {
+    TimesPer,
+    Every,
+}
+
+/// How the repeat frequency evolves over the projection.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrequencyGrowth {
+    Constant,
+    /// Compounded every month, negative for a shrinking frequency.
+    PercentPerMonth(f64),
+    /// Piecewise: the initial frequency is multiplied by the latest step started.
+    Schedule(Vec<GrowthStep>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrowthStep {
+    pub from_day: f64,
+    pub multiplier: f64,
+}
+
+impl FrequencyGrowth {
+    /// Multiplier applied to the initial frequency on day `t`.
+    pub fn factor(&self, t: f64, conf_time_unit: &ConfTimeUnit) -> f64 {
+        match self {
+            FrequencyGrowth::Constant => 1.0,
+            FrequencyGrowth::PercentPerMonth(percent) => {
+                let days_per_month = conf_time_unit.number_of_day_per_month();
+                if days_per_month <= 0.0 {
+                    return 1.0;
+                }
+                (1.0 + percent / 100.0).max(0.0).powf(t / days_per_month)
+            }
+            FrequencyGrowth::Schedule(steps) => steps.iter()
+                .filter(|step| step.from_day <= t)
+                .max_by(|a, b| a.from_day.total_cmp(&b.from_day))
+                .map_or(1.0, |step| step.multiplier),
+        }
+    }
+}
+
+/// How often an execution goes wrong, and the time it then takes to recover (rework, incident response...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Failure {
+    pub rate_percent: f64,
+    pub recovery_time: f64,
+    pub recovery_time_unit: TimeUnit,
+}
+
+impl Default for Failure {
+    fn default() -> Self {
+        Self { rate_percent: 0.0, recovery_time: 2.0, recovery_time_unit: TimeUnit::Hours }
+    }
+}
+
+impl Failure {
+    /// Recovery time averaged over every execution.
+    pub fn expected_hours(&self, conf: &ConfTimeUnit) -> f64 {
+        self.rate_percent / 100.0 * self.recovery_time_unit.to_hours(self.recovery_time, conf)
+    }
+}
+
+/// Part of the durations is spent waiting (CI, builds...), during which other work can partially go on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Multitasking {
+    pub before_waiting_percent: f64,
+    pub after_waiting_percent: f64,
+    /// Share of the waiting time put to good use, 0 when nothing else gets done meanwhile.
+    pub efficiency_percent: f64,
+    /// Lost when switching away from an execution that waits and back to it.
+    pub context_switch_time: f64,
+    pub context_switch_time_unit: TimeUnit,
+}
+
+impl Default for Multitasking {
+    fn default() -> Self {
+        Self {
+            before_waiting_percent: 50.0,
+            after_waiting_percent: 50.0,
+            efficiency_percent: 50.0,
+            context_switch_time: 2.0,
+            context_switch_time_unit: TimeUnit::Minutes,
+        }
+    }
+}
+
+impl Multitasking {
+    /// What `hours` of wall-clock time, `waiting_percent` of them waiting, really cost.
+    pub fn effective_hours(&self, hours: f64, waiting_percent: f64, conf: &ConfTimeUnit) -> f64 {
+        let waiting = hours * waiting_percent.clamp(0.0, 100.0) / 100.0;
+        if waiting <= 0.0 {
+            return hours;
+        }
+        let wasted = waiting * (1.0 - self.efficiency_percent.clamp(0.0, 100.0) / 100.0);
+        let context_switch = self.context_switch_time_unit.to_hours(self.context_switch_time, conf);
+        hours - waiting + (wasted + context_switch).min(waiting)
+    }
+}
+
+/// Executions of the improved process, compared with the current one: a cheaper process gets run more often.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InducedDemand {
+    Unchanged,
+    Multiplier(f64),
+    /// Executions per `repeat_count_time_unit`, as [`Scenario::executions_per_unit`].
+    Target(f64),
+}
+
+/// Focus lost every time the process interrupts other work, on top of its own duration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Refocus {
+    pub time: f64,
+    pub time_unit: TimeUnit,
+    /// Whether the improved process still interrupts, e.g. when it is sped up but not automated away.
+    pub after: bool,
+}
+
+impl Default for Refocus {
+    fn default() -> Self {
+        Self { time: 15.0, time_unit: TimeUnit::Minutes, after: false }
+    }
+}
+
+/// How fast executions move to the new process once it has shipped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AdoptionRamp {
+    Immediate,
+    /// From none to all of them over `days`.
+    Linear { days: f64 },
+    /// Quickly at first then slower, 95% of them after `days`.
+    Exponential { days: f64 },
+}
+
+/// Converts spent hours into money, disabled while `hourly_rate` is 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostModel {
+    pub hourly_rate: f64,
+    /// Multiplier applied on top of the hourly rate (taxes, office, tooling...), 1.0 for none.
+    pub overhead_multiplier: f64,
+    pub currency: String,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            hourly_rate: 0.0,
+            overhead_multiplier: 1.0,
+            currency: "$".to_string(),
+        }
+    }
+}
+
+impl CostModel {
+    pub fn enabled(&self) -> bool {
+        self.hourly_rate > 0.0
+    }
+
+    pub fn cost(&self, hours: f64) -> f64 {
+        hours * self.hourly_rate * self.overhead_multiplier
+    }
+
+    pub fn format(&self, amount: f64) -> String {
+        format!("{}{:.2}", self.currency, amount)
+    }
+}
+
+/// Shape of the distribution drawn between the min, likely and max of an estimate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distribution {
+    Triangular,
+    /// Beta distribution weighting the likely value 4 times more than the bounds.
+    Pert,
+}
+
+/// Lowest and highest plausible values of an input, the input itself being the most likely one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Estimate {
+    /// ±`spread` around `likely`, e.g. 0.25 for ±25%.
+    pub fn around(likely: f64, spread: f64) -> Self {
+        Self { min: likely * (1.0 - spread), max: likely * (1.0 + spread) }
+    }
+
+    /// Converts both bounds, e.g. when their unit changes.
+    pub fn scale(&mut self, factor: f64) {
+        self.min *= factor;
+        self.max *= factor;
+    }
+}
+
+/// Ranges of the inputs, in the same units as the inputs, used by the Monte Carlo simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Uncertainty {
+    pub distribution: Distribution,
+    pub before_taken_time: Estimate,
+    pub after_taken_time: Estimate,
+    pub invest_taken_time: Estimate,
+    pub repeat_count: Estimate,
+    pub runs: usize,
+}
+
+impl Uncertainty {
+    pub fn around(scenario: &Scenario, spread: f64) -> Self {
+        Self {
+            distribution: Distribution::Pert,
+            before_taken_time: Estimate::around(scenario.before_taken_time, spread),
+            after_taken_time: Estimate::around(scenario.after_taken_time, spread),
+            invest_taken_time: Estimate::around(scenario.invest_taken_time, spread),
+            repeat_count: Estimate::around(scenario.repeat_count, spread),
+            runs: 1000,
+        }
+    }
+}
+
+/// All the inputs of the projection, as entered in the top panel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scenario {
+    pub before_taken_time: f64,
+    pub before_taken_time_unit: TimeUnit,
+    /// The current process gets slower by this percentage every month (compounded), 0 when it does not.
+    pub before_degradation_percent_per_month: f64,
+    pub before_failure: Failure,
+    pub after_taken_time: f64,
+    pub after_taken_time_unit: TimeUnit,
+    pub after_failure: Failure,
+    /// `None` when every duration is hands-on time.
+    pub multitasking: Option<Multitasking>,
+    /// `None` when executions do not interrupt anything.
+    pub refocus: Option<Refocus>,
+    /// Share of the executions eventually going through the new process, 100 for all of them.
+    pub adoption_percent: f64,
+    pub adoption_ramp: AdoptionRamp,
+    pub induced_demand: InducedDemand,
+    pub invest_taken_time: f64,
+    pub invest_taken_time_unit: TimeUnit,
+    /// Hours per week each investor puts into the improvement, the current process keeps running meanwhile.
+    /// `None` when investing full time, the current process being put on hold.
+    pub invest_hours_per_week: Option<f64>,
+    /// The investment can only start on this day, the current process running as is until then.
+    pub start_delay_days: f64,
+    /// Chances the improvement works out, otherwise the investment is lost and the current process stays as is.
+    pub success_probability_percent: f64,
+    /// How much longer the investment may take than estimated, 1.0 when the estimate is right.
+    pub invest_overrun_factor: f64,
+    /// Upkeep of the improvement once shipped: `maintenance_time` every `maintenance_period_unit`, 0 for none.
+    pub maintenance_time: f64,
+    pub maintenance_time_unit: TimeUnit,
+    pub maintenance_period_unit: TimeUnit,
+    pub repeat_count: f64,
+    pub repeat_count_time_unit: TimeUnit,
+    pub repeat_mode: RepeatMode,
+    pub frequency_growth: FrequencyGrowth,
+    /// Number of people repeating the action, each of them `repeat_count` times.
+    pub people_count: usize,
+    /// When true only one person does the investment, otherwise every person has to invest.
+    pub invest_by_single_person: bool,
+    pub scale_number_of_day: usize,
+    /// The process stops existing after this many days, both lines flatten from there. `None` when it lives forever.
+    pub lifetime_days: Option<f64>,
+    /// When set, days of the projection are shown as dates, skipping non-working days.
+    pub start_date: Option<Date>,
+    pub conf_time_unit: ConfTimeUnit,
+    pub cost_model: CostModel,
+    /// Time later is worth less than time now, `None` to not discount.
+    pub discount_rate_percent_per_year: Option<f64>,
+    /// `None` while every input is a point estimate.
+    pub uncertainty: Option<Uncertainty>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            before_taken_time: 40.0,
+            before_taken_time_unit: TimeUnit::Seconds,
+            before_degradation_percent_per_month: 0.0,
+            before_failure: Failure::default(),
+            after_taken_time: 10.0,
+            after_taken_time_unit: TimeUnit::Seconds,
+            after_failure: Failure::default(),
+            multitasking: None,
+            refocus: None,
+            adoption_percent: 100.0,
+            adoption_ramp: AdoptionRamp::Immediate,
+            induced_demand: InducedDemand::Unchanged,
+            invest_taken_time: 2.0,
+            invest_taken_time_unit: TimeUnit::Hours,
+            invest_hours_per_week: None,
+            start_delay_days: 0.0,
+            success_probability_percent: 100.0,
+            invest_overrun_factor: 1.0,
+            maintenance_time: 0.0,
+            maintenance_time_unit: TimeUnit::Hours,
+            maintenance_period_unit: TimeUnit::Months,
+            repeat_count: 20.0,
+            repeat_count_time_unit: TimeUnit::Hours,
+            repeat_mode: RepeatMode::TimesPer,
+            frequency_growth: FrequencyGrowth::Constant,
+            people_count: 1,
+            invest_by_single_person: true,
+            scale_number_of_day: 90,
+            lifetime_days: None,
+            start_date: None,
+            conf_time_unit: ConfTimeUnit::default(),
+            cost_model: CostModel::default(),
+            discount_rate_percent_per_year: None,
+            uncertainty: None,
+        }
+    }
+}
+
+/// Where the after line (investment included) crosses the before line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BreakEven {
+    /// The investment is recovered on `day`, `hours` being the time spent by then.
+    PaysOffOnDay { day: f64, hours: f64 },
+    /// Keeping the current process during the investment would already cost more than the investment itself.
+    PaysOffDuringInvestment { day: f64, hours: f64 },
+    /// The improved process, maintenance included, does not take less time per day than the current one.
+    NeverAfterNotFaster,
+    /// The process stops existing before the investment would be recovered on `day`.
+    NeverBeforeSunset { day: f64, lifetime_days: f64 },
+    /// The daily saving fades out, as the frequency shrinks, before the investment is recovered.
+    NeverRecovered,
+    /// The investment is recovered, but only after `scale_number_of_day`.
+    NeverWithinHorizon { day: f64, hours: f64 },
+    /// The current process already fills the 24 hours of a day, so is the improved one: nothing can be saved.
+    CapacitySaturated,
+}
+
+impl BreakEven {
+    /// (day, hours) of the crossing point, when there is one.
+    pub fn point(&self) -> Option<(f64, f64)> {
+        match self {
+            BreakEven::PaysOffOnDay { day, hours }
+            | BreakEven::PaysOffDuringInvestment { day, hours }
+            | BreakEven::NeverWithinHorizon { day, hours } => Some((*day, *hours)),
+            BreakEven::NeverAfterNotFaster | BreakEven::CapacitySaturated | BreakEven::NeverBeforeSunset { .. } | BreakEven::NeverRecovered => None,
+        }
+    }
+}
+
+/// Output of [`Scenario::evaluate`]: what the UI needs to render the chart and the summary.
+#[derive(Clone, Debug)]
+pub struct Evaluation {
+    pub invest_time_in_hours: f64,
+    /// Number of working days needed to complete the investment.
+    pub invest_days: f64,
+    pub break_even: BreakEven,
+    /// Hours saved at `scale_number_of_day`.
+    pub saved_hours: f64,
+    pub invest_cost: f64,
+    pub before_daily_cost: f64,
+    pub after_daily_cost: f64,
+    /// Money saved at `scale_number_of_day`, investment not deducted.
+    pub saved_money: f64,
+    pub before_series: Vec<[f64; 2]>,
+    pub invest_series: Vec<[f64; 2]>,
+    pub after_series: Vec<[f64; 2]>,
+    /// Only with a discount rate.
+    pub discounted: Option<Discounted>,
+    /// Share of `saved_hours` coming from not having to get back to focus, only with refocusing.
+    pub refocus_saved_hours: Option<f64>,
+}
+
+/// Figures of [`Evaluation`] once every hour is discounted back to day 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Discounted {
+    /// `None` when not recovered within the search limit.
+    pub break_even_day: Option<f64>,
+    /// Hours saved at `scale_number_of_day`, investment deducted.
+    pub net_saved_hours: f64,
+    /// Same without discounting, to compare with.
+    pub undiscounted_net_saved_hours: f64,
+}
+
+impl Scenario {
+    pub fn investors_count(&self) -> f64 {
+        if self.invest_by_single_person {
+            1.0
+        } else {
+            self.people_count as f64
+        }
+    }
+
+    /// Total investment, summed over everyone investing.
+    pub fn invest_time_in_hours(&self) -> f64 {
+        self.invest_taken_time_unit.to_hours(self.invest_taken_time, &self.conf_time_unit) * self.investors_count()
+    }
+
+    /// Hours per working day each investor puts into the improvement.
+    pub fn invest_hours_per_day(&self) -> f64 {
+        let hours_per_day = self.conf_time_unit.number_of_hours_per_day();
+        match self.invest_hours_per_week {
+            Some(hours_per_week) => hours_per_day.min(hours_per_week / self.conf_time_unit.calendar.number_of_day_per_week()),
+            None => hours_per_day,
+        }
+    }
+
+    /// Investors work in parallel, so this is the number of days one of them needs.
+    /// Without any hour to invest, a non-zero investment never ships.
+    pub fn invest_days(&self) -> f64 {
+        let invest_hours = self.invest_taken_time_unit.to_hours(self.invest_taken_time, &self.conf_time_unit);
+        if invest_hours <= 0.0 {
+            return 0.0;
+        }
+        let invest_hours_per_day = self.invest_hours_per_day();
+        if invest_hours_per_day <= 0.0 {
+            return f64::INFINITY;
+        }
+        invest_hours / invest_hours_per_day
+    }
+
+    /// Day the improvement ships, once the delayed start and the investment are over.
+    pub fn ship_day(&self) -> f64 {
+        let invest_days = self.invest_days();
+        if invest_days.is_infinite() {
+            // never shipping: past every day the projection and the break-even search look at
+            return self.start_delay_days.max(self.scale_number_of_day as f64 * BREAK_EVEN_SEARCH_FACTOR);
+        }
+        self.start_delay_days + invest_days
+    }
+
+    /// Expected value of the scenario when it may fail or overrun, `None` when it can do neither.
+    /// Failing keeps the current process, so only the successful share of the executions and of the maintenance remains.
+    pub fn risk_adjusted(&self) -> Option<Scenario> {
+        if self.success_probability_percent >= 100.0 && self.invest_overrun_factor == 1.0 {
+            return None;
+        }
+        let success = self.success_probability_percent.clamp(0.0, 100.0) / 100.0;
+        Some(Scenario {
+            invest_taken_time: self.invest_taken_time * self.invest_overrun_factor,
+            adoption_percent: self.adoption_percent * success,
+            maintenance_time: self.maintenance_time * success,
+            success_probability_percent: 100.0,
+            invest_overrun_factor: 1.0,
+            ..self.clone()
+        })
+    }
+
+    /// Same scenario with the investment starting on day 0.
+    pub fn starting_now(&self) -> Scenario {
+        Scenario { start_delay_days: 0.0, ..self.clone() }
+    }
+
+    /// Extra time spent by day `t` because the investment starts later rather than on day 0.
+    pub fn waiting_cost_at(&self, t: f64) -> f64 {
+        self.improved_at(t) - self.starting_now().improved_at(t)
+    }
+
+    /// Share of the current process still running during the investment: all of it when investing part-time, only the
+    /// part of the people not investing otherwise.
+    pub fn running_share_during_investment(&self) -> f64 {
+        if self.invest_hours_per_week.is_some() {
+            return 1.0;
+        }
+        if self.people_count == 0 {
+            return 0.0;
+        }
+        ((self.people_count as f64 - self.investors_count()) / self.people_count as f64).max(0.0)
+    }
+
+    /// Time spent by the day the improvement ships when going for it.
+    fn improved_at_ship_day(&self) -> f64 {
+        let before_start = self.before_at(self.start_delay_days);
+        self.invest_time_in_hours() + before_start + self.running_share_during_investment() * (self.before_at(self.ship_day()) - before_start)
+    }
+
+    /// Executions per `repeat_count_time_unit`.
+    pub fn executions_per_unit(&self) -> f64 {
+        match self.repeat_mode {
+            RepeatMode::TimesPer => self.repeat_count,
+            RepeatMode::Every if self.repeat_count > 0.0 => 1.0 / self.repeat_count,
+            RepeatMode::Every => 0.0,
+        }
+    }
+
+    pub fn date_of_day(&self, day: f64) -> Option<Date> {
+        self.start_date.map(|start| self.conf_time_unit.calendar.working_day_date(start, day))
+    }
+
+    pub fn executions_per_day(&self) -> f64 {
+        self.repeat_count_time_unit.to_times_per_days(self.executions_per_unit(), &self.conf_time_unit)
+    }
+
+    /// Hours a day the process would need for one person, before clamping to 24 hours. Failures are part of it.
+    pub fn daily_demand_in_hours(&self, time_unit: &TimeUnit, time_taken: f64, failure: &Failure) -> f64 {
+        (time_unit.to_hours(time_taken, &self.conf_time_unit) + failure.expected_hours(&self.conf_time_unit)) * self.executions_per_day()
+    }
+
+    pub fn time_taken_per_day_in_hours(&self, t: f64, time_unit: &TimeUnit, time_taken: f64, failure: &Failure) -> (f64, f64) {
+        (t, 24.0_f64.min(self.daily_demand_in_hours(time_unit, time_taken, failure)) * self.people_count as f64 * t)
+    }
+
+    /// Expected hours per execution of the current process on day 0, failures included.
+    pub fn before_execution_hours(&self) -> f64 {
+        self.before_execution_hours_at(0.0)
+    }
+
+    /// Expected hours per execution of the improved process, failures included.
+    pub fn after_execution_hours(&self) -> f64 {
+        let hours = self.after_taken_time_unit.to_hours(self.after_taken_time, &self.conf_time_unit);
+        self.effective_hours(hours, |multitasking| multitasking.after_waiting_percent) + self.after_failure.expected_hours(&self.conf_time_unit)
+            + self.refocus_hours(true)
+    }
+
+    /// Maintenance is done once for the whole team, whatever the number of people.
+    pub fn maintenance_per_day_in_hours(&self) -> f64 {
+        self.maintenance_time_unit.to_hours(self.maintenance_time, &self.conf_time_unit) * self.maintenance_period_unit.to_times_per_days(1.0, &self.conf_time_unit)
+    }
+
+    /// Daily cost of the current process on the first day.
+    pub fn before_per_day_in_hours(&self) -> f64 {
+        let hours = self.before_taken_time_unit.to_hours(self.before_taken_time, &self.conf_time_unit);
+        let effective_hours = self.effective_hours(hours, |multitasking| multitasking.before_waiting_percent) + self.refocus_hours(false);
+        self.time_taken_per_day_in_hours(1.0, &TimeUnit::Hours, effective_hours, &self.before_failure).1
+    }
+
+    /// Daily cost of the improved process on the first day once fully adopted, maintenance included.
+    pub fn after_per_day_in_hours(&self) -> f64 {
+        let hours_per_execution = self.adopted_execution_hours(self.adoption_percent / 100.0, self.before_execution_hours());
+        24.0_f64.min(hours_per_execution * self.executions_per_day()) * self.people_count as f64 + self.maintenance_per_day_in_hours()
+    }
+
+    /// How many times more often the improved process runs than the current one.
+    pub fn induced_demand_factor(&self) -> f64 {
+        match self.induced_demand {
+            InducedDemand::Unchanged => 1.0,
+            InducedDemand::Multiplier(multiplier) => multiplier.max(0.0),
+            InducedDemand::Target(target) if self.executions_per_unit() > 0.0 => target.max(0.0) / self.executions_per_unit(),
+            InducedDemand::Target(_) => 1.0,
+        }
+    }
+
+    /// Hours per execution of the current process when `adoption` of them use the improved process and the others take
+    /// `before_hours`. The improved process running more often, its extra executions are spread over the current ones.
+    pub fn adopted_execution_hours(&self, adoption: f64, before_hours: f64) -> f64 {
+        let after_hours = self.after_execution_hours() * self.induced_demand_factor();
+        if adoption < 1.0 {
+            adoption * after_hours + (1.0 - adoption) * before_hours
+        } else {
+            after_hours
+        }
+    }
+
+    /// Executions gained by day `t` thanks to the improved process running more often, for the whole team.
+    pub fn extra_executions_at(&self, t: f64) -> f64 {
+        let extra = self.induced_demand_factor() - 1.0;
+        let rate = |t: f64| if self.is_alive(t) { self.adoption_at(t) * extra * self.executions_per_day_at(t) * self.people_count as f64 } else { 0.0 };
+        let ship_day = self.ship_day();
+        if t <= ship_day {
+            return 0.0;
+        }
+        self.integrate(rate, ship_day, t)
+    }
+
+    /// Days during which the process has been running by day `t`.
+    pub fn alive_days(&self, t: f64) -> f64 {
+        self.lifetime_days.map_or(t, |lifetime| t.min(lifetime))
+    }
+
+    fn is_alive(&self, t: f64) -> bool {
+        self.lifetime_days.is_none_or(|lifetime| t <= lifetime)
+    }
+
+    pub fn executions_per_day_at(&self, t: f64) -> f64 {
+        self.executions_per_day() * self.frequency_growth.factor(t, &self.conf_time_unit)
+    }
+
+    /// Multiplier applied to the time the current process takes on day `t`.
+    pub fn before_degradation_at(&self, t: f64) -> f64 {
+        let days_per_month = self.conf_time_unit.number_of_day_per_month();
+        if self.before_degradation_percent_per_month == 0.0 || days_per_month <= 0.0 {
+            return 1.0;
+        }
+        (1.0 + self.before_degradation_percent_per_month / 100.0).max(0.0).powf(t / days_per_month)
+    }
+
+    fn daily_cost_at(&self, t: f64, hours_per_execution: f64) -> f64 {
+        if !self.is_alive(t) {
+            return 0.0;
+        }
+        24.0_f64.min(hours_per_execution * self.executions_per_day_at(t)) * self.people_count as f64
+    }
+
+    /// Expected hours per execution of the current process on day `t`, degradation and failures included.
+    pub fn before_execution_hours_at(&self, t: f64) -> f64 {
+        let hours = self.before_taken_time_unit.to_hours(self.before_taken_time * self.before_degradation_at(t), &self.conf_time_unit);
+        self.effective_hours(hours, |multitasking| multitasking.before_waiting_percent) + self.before_failure.expected_hours(&self.conf_time_unit)
+            + self.refocus_hours(false)
+    }
+
+    /// Focus lost per execution of the current process, or of the improved one when `after`.
+    pub fn refocus_hours(&self, after: bool) -> f64 {
+        match &self.refocus {
+            Some(refocus) if !after || refocus.after => refocus.time_unit.to_hours(refocus.time, &self.conf_time_unit),
+            _ => 0.0,
+        }
+    }
+
+    /// Hours saved by day `t` thanks to refocusing less, part of [`Scenario::saved_hours_at`], `None` without refocusing.
+    pub fn refocus_saved_hours_at(&self, t: f64) -> Option<f64> {
+        self.refocus.as_ref()?;
+        Some(self.saved_hours_at(t) - Scenario { refocus: None, ..self.clone() }.saved_hours_at(t))
+    }
+
+    /// Cost of `hours` of an execution once the waiting part is shared with other work, recovering from failures being hands-on.
+    fn effective_hours(&self, hours: f64, waiting_percent: fn(&Multitasking) -> f64) -> f64 {
+        match &self.multitasking {
+            Some(multitasking) => multitasking.effective_hours(hours, waiting_percent(multitasking), &self.conf_time_unit),
+            None => hours,
+        }
+    }
+
+    /// Same scenario counting every duration as hands-on time, `None` when it already does.
+    pub fn wall_clock(&self) -> Option<Scenario> {
+        self.multitasking.as_ref()?;
+        Some(Scenario { multitasking: None, ..self.clone() })
+    }
+
+    /// Share of the executions going through the new process on day `t`, once it has shipped.
+    pub fn adoption_at(&self, t: f64) -> f64 {
+        let share = self.adoption_percent / 100.0;
+        let since_shipped = (t - self.ship_day()).max(0.0);
+        match self.adoption_ramp {
+            AdoptionRamp::Immediate => share,
+            AdoptionRamp::Linear { days } if days > 0.0 => share * (since_shipped / days).min(1.0),
+            AdoptionRamp::Exponential { days } if days > 0.0 => share * (1.0 - (-3.0 * since_shipped / days).exp()),
+            AdoptionRamp::Linear { .. } | AdoptionRamp::Exponential { .. } => share,
+        }
+    }
+
+    /// Hours spent on day `t` with the current process.
+    pub fn before_rate_at(&self, t: f64) -> f64 {
+        self.daily_cost_at(t, self.before_execution_hours_at(t))
+    }
+
+    /// Hours spent on day `t` with the improved process, maintenance included. Executions not using it yet cost as much as before.
+    pub fn after_rate_at(&self, t: f64) -> f64 {
+        if !self.is_alive(t) {
+            return 0.0;
+        }
+        let hours_per_execution = self.adopted_execution_hours(self.adoption_at(t), self.before_execution_hours_at(t));
+        self.daily_cost_at(t, hours_per_execution) + self.maintenance_per_day_in_hours()
+    }
+
+    /// Hours spent on day `t` when going for the improvement: the current process until the investment starts, the
+    /// investment, then the improved process.
+    pub fn improved_rate_at(&self, t: f64) -> f64 {
+        if t < self.start_delay_days {
+            self.before_rate_at(t)
+        } else if t < self.ship_day() {
+            let investing = self.invest_hours_per_day() * self.investors_count();
+            investing + self.running_share_during_investment() * self.before_rate_at(t)
+        } else {
+            self.after_rate_at(t)
+        }
+    }
+
+    /// Weight of an hour spent on day `t` compared with one spent on day 0.
+    pub fn discount_factor_at(&self, t: f64) -> f64 {
+        let days_per_year = self.conf_time_unit.number_of_day_per_year();
+        match self.discount_rate_percent_per_year {
+            Some(percent) if days_per_year > 0.0 => (1.0 + percent / 100.0).max(f64::EPSILON).powf(-t / days_per_year),
+            _ => 1.0,
+        }
+    }
+
+    /// Discounted hours saved between the days `from` and `to` by going for the improvement, investment deducted.
+    fn discounted_saving(&self, from: f64, to: f64) -> f64 {
+        let rate = |t: f64| (self.before_rate_at(t) - self.improved_rate_at(t)) * self.discount_factor_at(t);
+        let mut bounds = vec![from];
+        bounds.extend([self.start_delay_days, self.ship_day()].into_iter().filter(|day| *day > from && *day < to));
+        bounds.push(to);
+        bounds.windows(2).map(|bounds| self.integrate(rate, bounds[0], bounds[1])).sum()
+    }
+
+    /// Net hours saved by day `t` when every hour is discounted back to day 0.
+    pub fn discounted_net_saved_hours_at(&self, t: f64) -> f64 {
+        self.discounted_saving(0.0, t)
+    }
+
+    /// First day the discounted saving covers the investment, walking day by day up to `limit`.
+    fn discounted_break_even_within(&self, limit: f64) -> Option<f64> {
+        let ship_day = self.ship_day();
+        let mut saved = self.discounted_saving(0.0, ship_day);
+        if saved >= 0.0 {
+            return Some(ship_day);
+        }
+        let mut t = ship_day;
+        while t < limit {
+            let next = (t + 1.0).min(limit);
+            if saved + self.discounted_saving(t, next) >= 0.0 {
+                let (mut lo, mut hi) = (t, next);
+                for _ in 0..BISECTION_ITERATIONS {
+                    let mid = (lo + hi) / 2.0;
+                    if saved + self.discounted_saving(t, mid) < 0.0 {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                return Some(hi);
+            }
+            saved += self.discounted_saving(t, next);
+            t = next;
+        }
+        None
+    }
+
+    /// Only computed with a discount rate, the discounted break-even is only searched when `break_even` has one.
+    pub fn discounted(&self, break_even: &BreakEven) -> Option<Discounted> {
+        self.discount_rate_percent_per_year?;
+        let scale = self.scale_number_of_day as f64;
+        let break_even_day = match break_even.point() {
+            Some(_) => self.discounted_break_even_within(scale * BREAK_EVEN_SEARCH_FACTOR),
+            None => None,
+        };
+        Some(Discounted {
+            break_even_day,
+            net_saved_hours: self.discounted_net_saved_hours_at(scale),
+            undiscounted_net_saved_hours: self.before_at(scale) - self.improved_at(scale),
+        })
+    }
+
+    /// Days on which the daily rates jump, integrals are split there to stay exact.
+    fn breakpoints(&self) -> Vec<f64> {
+        let mut breakpoints: Vec<f64> = self.lifetime_days.into_iter().collect();
+        if let FrequencyGrowth::Schedule(steps) = &self.frequency_growth {
+            breakpoints.extend(steps.iter().map(|step| step.from_day));
+        }
+        if let AdoptionRamp::Linear { days } = self.adoption_ramp {
+            breakpoints.extend([self.ship_day(), self.ship_day() + days]);
+        }
+        breakpoints
+    }
+
+    /// Integral of `rate` between the days `from` and `to`, negative when `to` is before `from`.
+    fn integrate(&self, rate: impl Fn(f64) -> f64, from: f64, to: f64) -> f64 {
+        if to < from {
+            return -self.integrate(rate, to, from);
+        }
+        let mut bounds = vec![from];
+        bounds.extend(self.breakpoints().into_iter().filter(|day| *day > from && *day < to));
+        bounds.push(to);
+        bounds.windows(2).map(|bounds| gauss_legendre(&rate, bounds[0], bounds[1])).sum()
+    }
+
+    /// Cumulated `rate`, worth `initial` on day `start`, on each of `days`.
+    fn cumulative(&self, rate: impl Fn(f64) -> f64, start: f64, initial: f64, days: &[f64]) -> Vec<f64> {
+        let mut previous = (start, initial);
+        days.iter().map(|day| {
+            previous = (*day, previous.1 + self.integrate(&rate, previous.0, *day));
+            previous.1
+        }).collect()
+    }
+
+    pub fn before_at(&self, t: f64) -> f64 {
+        self.integrate(|t| self.before_rate_at(t), 0.0, t)
+    }
+
+    pub fn invest_at(&self, t: f64) -> f64 {
+        self.invest_time_in_hours().min((t - self.start_delay_days).max(0.0) * self.invest_hours_per_day() * self.investors_count())
+    }
+
+    pub fn after_at(&self, t: f64) -> f64 {
+        self.improved_at_ship_day() + self.integrate(|t| self.after_rate_at(t), self.ship_day(), t)
+    }
+
+    /// Time spent by day `t` when going for the improvement, investment included.
+    pub fn improved_at(&self, t: f64) -> f64 {
+        if t >= self.ship_day() {
+            return self.after_at(t);
+        }
+        let before_start = self.before_at(t.min(self.start_delay_days));
+        self.invest_at(t) + before_start + self.running_share_during_investment() * (self.before_at(t) - before_start)
+    }
+
+    /// Hours saved by day `t` if the improved process had been there from the day the investment starts, investment not
+    /// deducted. Nothing is saved while waiting for a delayed start.
+    pub fn saved_hours_at(&self, t: f64) -> f64 {
+        let start = self.start_delay_days;
+        self.integrate(|t| self.before_rate_at(t) - self.after_rate_at(t), start, t.max(start))
+    }
+
+    /// [`Scenario::before_at`] on each of the sorted `days`, without integrating from day 0 each time.
+    pub fn before_series(&self, days: &[f64]) -> Vec<f64> {
+        self.cumulative(|t| self.before_rate_at(t), 0.0, 0.0, days)
+    }
+
+    /// [`Scenario::improved_at`] on each of the sorted `days`.
+    pub fn improved_series(&self, days: &[f64]) -> Vec<f64> {
+        let ship_day = self.ship_day();
+        let investing = days.iter().take_while(|day| **day < ship_day).count();
+        // from the start, only the running share of the current process is still paid
+        let running_share = self.running_share_during_investment();
+        let before_start = self.before_at(self.start_delay_days);
+        let mut series: Vec<f64> = days[..investing].iter().zip(self.before_series(&days[..investing]))
+            .map(|(day, before)| self.invest_at(*day) + if *day < self.start_delay_days { before } else { before_start + running_share * (before - before_start) })
+            .collect();
+        series.extend(self.cumulative(|t| self.after_rate_at(t), ship_day, self.improved_at_ship_day(), &days[investing..]));
+        series
+    }
+
+    pub fn intersection(&self) -> BreakEven {
+        let limit = self.scale_number_of_day as f64 * BREAK_EVEN_SEARCH_FACTOR;
+        let Some(lifetime_days) = self.lifetime_days else {
+            return self.break_even_within(limit);
+        };
+        // until the sunset both lines are the same as for a process living forever
+        let forever = Scenario { lifetime_days: None, ..self.clone() };
+        let break_even = forever.break_even_within(limit);
+        match break_even.point() {
+            Some((day, _)) if day >= lifetime_days => BreakEven::NeverBeforeSunset { day, lifetime_days },
+            _ => break_even,
+        }
+    }
+
+    /// Whether the investment is recovered within `scale_number_of_day`, cheaper than [`Scenario::intersection`].
+    pub fn pays_off(&self) -> bool {
+        matches!(self.intersection_within_horizon(), BreakEven::PaysOffOnDay { .. } | BreakEven::PaysOffDuringInvestment { .. })
+    }
+
+    fn intersection_within_horizon(&self) -> BreakEven {
+        let break_even = self.break_even_within(self.scale_number_of_day as f64);
+        match (self.lifetime_days, break_even.point()) {
+            (Some(lifetime_days), Some((day, _))) if day >= lifetime_days => BreakEven::NeverBeforeSunset { day, lifetime_days },
+            _ => break_even,
+        }
+    }
+
+    /// Whether the daily rates are the same from day 0 on, both lines are then straight and the break-even has a closed form.
+    pub fn has_constant_rates(&self) -> bool {
+        self.steady_from() == Some(0.0)
+    }
+
+    /// Day from which the daily rates do not change anymore, `None` when they keep changing.
+    fn steady_from(&self) -> Option<f64> {
+        if self.before_degradation_percent_per_month != 0.0 {
+            return None;
+        }
+        let adopted = match self.adoption_ramp {
+            AdoptionRamp::Immediate => 0.0,
+            AdoptionRamp::Linear { days } => self.ship_day() + days,
+            AdoptionRamp::Exponential { days } if days > 0.0 => return None,
+            AdoptionRamp::Exponential { .. } => 0.0,
+        };
+        let growth_ends = match &self.frequency_growth {
+            FrequencyGrowth::Constant => 0.0,
+            FrequencyGrowth::PercentPerMonth(percent) if *percent == 0.0 => 0.0,
+            FrequencyGrowth::PercentPerMonth(_) => return None,
+            FrequencyGrowth::Schedule(steps) => steps.iter().map(|step| step.from_day).fold(0.0, f64::max),
+        };
+        Some(growth_ends.max(adopted).max(self.lifetime_days.unwrap_or(0.0)))
+    }
+
+    /// Walks day by day from the end of the investment until the after line, investment included, crosses the before line.
+    /// Once the daily rates are steady, or past `limit`, the crossing is extrapolated from the daily saving reached there.
+    fn break_even_within(&self, limit: f64) -> BreakEven {
+        let ship_day = self.ship_day();
+        let saving_at = |t: f64| self.before_rate_at(t) - self.after_rate_at(t);
+        // time spent with the improvement minus time spent without it
+        let mut gap = self.improved_at_ship_day() - self.before_at(ship_day);
+        let mut ever_saving = saving_at(ship_day) > 0.0;
+        if gap <= 0.0 && ever_saving {
+            return BreakEven::PaysOffDuringInvestment { day: ship_day, hours: self.improved_at_ship_day() };
+        }
+        let mut t = ship_day;
+        let steady_from = self.steady_from();
+        while t < limit && steady_from.is_none_or(|steady_from| t < steady_from) {
+            let next = (t + 1.0).min(limit);
+            let next_gap = gap - self.integrate(saving_at, t, next);
+            ever_saving |= saving_at(next) > 0.0;
+            if gap > 0.0 && next_gap <= 0.0 {
+                let (mut lo, mut hi) = (t, next);
+                for _ in 0..BISECTION_ITERATIONS {
+                    let mid = (lo + hi) / 2.0;
+                    if gap - self.integrate(saving_at, t, mid) > 0.0 {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                return self.break_even_on(hi);
+            }
+            gap = next_gap;
+            t = next;
+        }
+        if !ever_saving {
+            if self.after_execution_hours() < self.before_execution_hours() && self.before_execution_hours() * self.executions_per_day() > 24.0 {
+                return BreakEven::CapacitySaturated;
+            }
+            return BreakEven::NeverAfterNotFaster;
+        }
+        let saving = saving_at(t);
+        if saving <= 0.0 {
+            return BreakEven::NeverRecovered;
+        }
+        // still fading out by the limit: assuming a geometric decay, what is left to save may not cover the gap
+        let previous_saving = saving_at(t - 1.0);
+        let steady = steady_from.is_some_and(|steady_from| t >= steady_from);
+        if !steady && saving < previous_saving {
+            let ratio = saving / previous_saving;
+            if gap > saving * ratio / (1.0 - ratio) {
+                return BreakEven::NeverRecovered;
+            }
+        }
+        self.break_even_on(t + gap.max(0.0) / saving)
+    }
+
+    fn break_even_on(&self, day: f64) -> BreakEven {
+        let hours = self.before_at(day);
+        if day > self.scale_number_of_day as f64 {
+            BreakEven::NeverWithinHorizon { day, hours }
+        } else {
+            BreakEven::PaysOffOnDay { day, hours }
+        }
+    }
+
+    /// Removes a custom unit, inputs using it are converted back to the unit it is defined from.
+    pub fn remove_custom_unit(&mut self, index: usize) {
+        let removed = self.conf_time_unit.custom_units.remove(index);
+        let shift = |unit: &mut TimeUnit| -> bool {
+            match unit {
+                TimeUnit::Custom(i) if *i == index => {
+                    *unit = removed.unit.clone();
+                    true
+                }
+                TimeUnit::Custom(i) if *i > index => {
+                    *i -= 1;
+                    false
+                }
+                _ => false,
+            }
+        };
+        // the uncertainty ranges are in the same units as the inputs
+        let mut uncertainty = self.uncertainty.as_mut();
+        if shift(&mut self.before_taken_time_unit) {
+            self.before_taken_time *= removed.value;
+            if let Some(uncertainty) = uncertainty.as_mut() {
+                uncertainty.before_taken_time.scale(removed.value);
+            }
+        }
+        if shift(&mut self.after_taken_time_unit) {
+            self.after_taken_time *= removed.value;
+            if let Some(uncertainty) = uncertainty.as_mut() {
+                uncertainty.after_taken_time.scale(removed.value);
+            }
+        }
+        if shift(&mut self.before_failure.recovery_time_unit) {
+            self.before_failure.recovery_time *= removed.value;
+        }
+        if shift(&mut self.after_failure.recovery_time_unit) {
+            self.after_failure.recovery_time *= removed.value;
+        }
+        if let Some(multitasking) = self.multitasking.as_mut() {
+            if shift(&mut multitasking.context_switch_time_unit) {
+                multitasking.context_switch_time *= removed.value;
+            }
+        }
+        if let Some(refocus) = self.refocus.as_mut() {
+            if shift(&mut refocus.time_unit) {
+                refocus.time *= removed.value;
+            }
+        }
+        if shift(&mut self.invest_taken_time_unit) {
+            self.invest_taken_time *= removed.value;
+            if let Some(uncertainty) = uncertainty.as_mut() {
+                uncertainty.invest_taken_time.scale(removed.value);
+            }
+        }
+        if shift(&mut self.maintenance_time_unit) {
+            self.maintenance_time *= removed.value;
+        }
+        if shift(&mut self.maintenance_period_unit) && removed.value > 0.0 {
+            self.maintenance_time /= removed.value;
+        }
+        if shift(&mut self.repeat_count_time_unit) {
+            let factor = match self.repeat_mode {
+                RepeatMode::TimesPer if removed.value > 0.0 => 1.0 / removed.value,
+                RepeatMode::TimesPer => 0.0,
+                RepeatMode::Every => removed.value,
+            };
+            self.repeat_count *= factor;
+            if let Some(uncertainty) = uncertainty.as_mut() {
+                uncertainty.repeat_count.scale(factor);
+            }
+            // the target is per repeat unit as well
+            if let InducedDemand::Target(target) = &mut self.induced_demand {
+                *target = if removed.value > 0.0 { *target / removed.value } else { 0.0 };
+            }
+        }
+    }
+
+    pub fn evaluate(&self) -> Evaluation {
+        let invest_days = self.invest_days();
+        let scale = self.scale_number_of_day as f64;
+        let saved_hours = self.saved_hours_at(scale);
+        let break_even = self.intersection();
+        let discounted = self.discounted(&break_even);
+        let days = sample(0.0, scale, self.scale_number_of_day);
+        let ship_day = self.ship_day();
+        let after_days = sample(ship_day, scale, self.scale_number_of_day);
+        // about a point a day while investing, no more than for the whole projection
+        let invest_days_sample = sample(self.start_delay_days, ship_day, ((ship_day - self.start_delay_days) as usize).min(self.scale_number_of_day));
+        Evaluation {
+            invest_time_in_hours: self.invest_time_in_hours(),
+            invest_days,
+            break_even,
+            saved_hours,
+            invest_cost: self.cost_model.cost(self.invest_time_in_hours()),
+            before_daily_cost: self.cost_model.cost(self.before_per_day_in_hours()),
+            after_daily_cost: self.cost_model.cost(self.after_per_day_in_hours()),
+            saved_money: self.cost_model.cost(saved_hours),
+            before_series: days.iter().copied().zip(self.before_series(&days)).map(|(t, hours)| [t, hours]).collect(),
+            invest_series: invest_days_sample.iter().copied().zip(self.improved_series(&invest_days_sample)).map(|(t, hours)| [t, hours]).collect(),
+            after_series: after_days.iter().copied().zip(self.cumulative(|t| self.after_rate_at(t), ship_day, self.improved_at_ship_day(), &after_days))
+                .map(|(t, hours)| [t, hours]).collect(),
+            discounted,
+            refocus_saved_hours: self.refocus_saved_hours_at(scale),
+        }
+    }
+}
+
+/// `points` days evenly spread between `start` and `end` (both included).
+fn sample(start: f64, end: f64, points: usize) -> Vec<f64> {
+    let points = points.max(2);
+    let increment = (end - start) / (points - 1) as f64;
+    (0..points).map(|i| start + i as f64 * increment).collect()
+}
+
+/// Two points Gauss-Legendre quadrature on each day: exact for cubic rates, and never evaluates `rate` on the bounds,
+/// where it may jump.
+fn gauss_legendre(rate: impl Fn(f64) -> f64, from: f64, to: f64) -> f64 {
+    let panels = (to - from).ceil().max(1.0);
+    let width = (to - from) / panels;
+    let offset = width / 2.0 / 3.0_f64.sqrt();
+    (0..panels as usize).map(|i| {
+        let middle = from + (i as f64 + 0.5) * width;
+        (rate(middle - offset) + rate(middle + offset)) * width / 2.0
+    }).sum()
+}
+
+pub fn label_hours_to_minutes(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
+    let seconds = TimeUnit::Hours.to_seconds(val, conf) as usize;
+    let minutes = seconds / 60;
+    let remaining_seconds = seconds % 60;
+    if remaining_seconds > 0 {
+        if short {
+            format!("{}m {}s", minutes, remaining_seconds)
+        } else {
+            format!("{} minutes and {} seconds", minutes, remaining_seconds)
+        }
+    } else if short {
+        format!("{}m", minutes)
+    } else {
+        format!("{} minutes", minutes)
+    }
+}
+
+pub fn label_hours_to_hours_minutes(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
+    let seconds = TimeUnit::Hours.to_seconds(val, conf) as usize;
+    let hours = seconds / 60 / 60;
+    let minutes = seconds / 60 % 60;
+    if minutes > 0 {
+        if short {
+            format!("{}h {}m", hours, minutes)
+        } else {
+            format!("{} hours and {} minutes", hours, minutes)
+        }
+    } else if short {
+        format!("{}h", hours)
+    } else {
+        format!("{} hours", hours)
+    }
+}
+
+pub fn label_hours_to_days_hours(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
+    let hours_per_day = conf.number_of_hours_per_day();
+    let days = (val / hours_per_day).floor();
+    let hours = (val - days * hours_per_day).floor();
+    if short {
+        if hours == 0.0 {
+            format!("{}d", days)
+        } else {
+            format!("{}d {}h", days, hours)
+        }
+    } else if hours == 0.0 {
+        format!("{} days", days)
+    } else {
+        format!("{} days and {} hours", days, hours)
+    }
+}
+
+pub fn value_to_human_duration(val: f64, short: bool, conf: &ConfTimeUnit) -> String {
+    if val < 0.0 {
+        return format!("-{}", value_to_human_duration(-val, short, conf));
+    }
+    if val < 0.016 {
+        if short {
+            format!("{}s", TimeUnit::Hours.to_seconds(val, conf))
+        } else {
+            format!("{} seconds", TimeUnit::Hours.to_seconds(val, conf))
+        }
+    } else if val < 1.0 {
+        label_hours_to_minutes(val, short, conf)
+    } else if val < conf.number_of_hours_per_day() {
+        label_hours_to_hours_minutes(val, short, conf)
+    } else {
+        label_hours_to_days_hours(val, short, conf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calendar::{Calendar, DAYS_PER_YEAR};
+    use crate::model::{value_to_human_duration, AdoptionRamp, BreakEven, ConfTimeUnit, CostModel, CustomTimeUnit, Failure, FrequencyGrowth, GrowthStep, InducedDemand, Multitasking, Refocus, RepeatMode, Scenario, TimeUnit, Uncertainty};
+
+    #[test]
+    fn conversion_to_hours() {
+        let conf_time_unit = ConfTimeUnit {
+            calendar: Calendar::full_time(8.0, 5),
+            ..ConfTimeUnit::default()
+        };
+        // 5 days a week, no day off: 260.89 days per year
+        let number_of_day_per_year = DAYS_PER_YEAR / 7.0 * 5.0;
+        assert_eq!(TimeUnit::Seconds.to_hours(2.0, &conf_time_unit), 0.0005555555555555556);
+        assert_eq!(TimeUnit::Minutes.to_hours(2.0, &conf_time_unit), 0.03333333333333333);
+        assert_eq!(TimeUnit::Hours.to_hours(2.0, &conf_time_unit), 2.0);
+        assert_eq!(TimeUnit::Days.to_hours(2.0, &conf_time_unit), 16.0);
+        assert_eq!(TimeUnit::Weeks.to_hours(2.0, &conf_time_unit), 80.0);
+        assert!((TimeUnit::Months.to_hours(2.0, &conf_time_unit) - 2.0 * 8.0 * number_of_day_per_year / 12.0).abs() < 1e-9);
+        assert!((TimeUnit::Quarters.to_hours(2.0, &conf_time_unit) - 2.0 * 8.0 * number_of_day_per_year / 4.0).abs() < 1e-9);
+        assert!((TimeUnit::Years.to_hours(2.0, &conf_time_unit) - 2.0 * 8.0 * number_of_day_per_year).abs() < 1e-9);
+        assert_eq!(TimeUnit::Years.to_seconds(1.0, &conf_time_unit), TimeUnit::Years.to_hours(1.0, &conf_time_unit) * 3600.0);
+    }
+
+    #[test]
+    fn conversion_time_per_day() {
+        let conf_time_unit = ConfTimeUnit {
+            calendar: Calendar { vacation_days_per_year: 20.0, ..Calendar::full_time(8.0, 5) },
+            ..ConfTimeUnit::default()
+        };
+        let number_of_day_per_year = DAYS_PER_YEAR / 7.0 * 5.0 - 20.0;
+        assert_eq!(TimeUnit::Seconds.to_times_per_days(10.0, &conf_time_unit), 288000.0);
+        assert_eq!(TimeUnit::Minutes.to_times_per_days(10.0, &conf_time_unit), 4800.0);
+        assert_eq!(TimeUnit::Hours.to_times_per_days(10.0, &conf_time_unit), 80.0);
+        assert_eq!(TimeUnit::Days.to_times_per_days(10.0, &conf_time_unit), 10.0);
+        assert_eq!(TimeUnit::Weeks.to_times_per_days(10.0, &conf_time_unit), 2.0);
+        assert!((TimeUnit::Months.to_times_per_days(10.0, &conf_time_unit) - 10.0 * 12.0 / number_of_day_per_year).abs() < 1e-12);
+        assert!((TimeUnit::Quarters.to_times_per_days(9.0, &conf_time_unit) - 9.0 * 4.0 / number_of_day_per_year).abs() < 1e-12);
+        assert!((TimeUnit::Years.to_times_per_days(10.0, &conf_time_unit) - 10.0 / number_of_day_per_year).abs() < 1e-12);
+    }
+
+    #[test]
+    fn time_taken() {
+        // I repeat this action 10 times per day and it takes me 40 seconds each time
+        let mut scenario = Scenario {
+            repeat_count: 10.0,
+            repeat_count_time_unit: TimeUnit::Days,
+            before_taken_time: 40.0,
+            before_taken_time_unit: TimeUnit::Seconds,
+            ..Scenario::default()
+        };
+        assert_eq!(scenario.time_taken_per_day_in_hours(1.0, &scenario.before_taken_time_unit, scenario.before_taken_time, &scenario.before_failure).1.round(),
+                   TimeUnit::Seconds.to_hours(40.0 * 10.0 /* 10 time per day */, &scenario.conf_time_unit).round());
+
+        // I repeat this action 10 times per hour and it takes me 2 minutes each time
+        scenario.repeat_count = 10.0;
+        scenario.repeat_count_time_unit = TimeUnit::Hours;
+        scenario.before_taken_time = 2.0;
+        scenario.before_taken_time_unit = TimeUnit::Minutes;
+        assert_eq!(scenario.time_taken_per_day_in_hours(1.0, &scenario.before_taken_time_unit, scenario.before_taken_time, &scenario.before_failure).1.round(),
+                   TimeUnit::Minutes.to_hours(2.0 * 80.0 /*80 times per day (8 h * 10 time)*/, &scenario.conf_time_unit).round());
+
+        // I repeat this action 2 times per week and it takes me 4 hours each time
+        scenario.repeat_count = 2.0;
+        scenario.repeat_count_time_unit = TimeUnit::Weeks;
+        scenario.before_taken_time = 4.0;
+        scenario.before_taken_time_unit = TimeUnit::Hours;
+        assert_eq!(scenario.time_taken_per_day_in_hours(1.0, &scenario.before_taken_time_unit, scenario.before_taken_time, &scenario.before_failure).1.round(),
+                   TimeUnit::Hours.to_hours(4.0 * 0.4 /* 0.4 times per day (2 time / 5 day)*/, &scenario.conf_time_unit).round());
+    }
+
+    #[test]
+    fn evaluate_default_scenario() {
+        let scenario = Scenario::default();
+        let evaluation = scenario.evaluate();
+        // 20 times per hour, 8 hours per day: 160 executions per day
+        // before: 160 * 40s = 1.777h per day, after: 160 * 10s = 0.444h per day, invest: 2h = 0.25 day
+        assert_eq!(evaluation.invest_days, 0.25);
+        let (day, _) = evaluation.break_even.point().unwrap();
+        assert!((day - 17.0 / 12.0).abs() < 1e-9);
+        assert!((evaluation.saved_hours - 120.0).abs() < 1e-9);
+        assert_eq!(evaluation.before_series.len(), scenario.scale_number_of_day);
+        assert_eq!(evaluation.after_series.first().unwrap()[1], evaluation.invest_time_in_hours);
+    }
+
+    #[test]
+    fn break_even_outcomes() {
+        let mut scenario = Scenario::default();
+        assert!(matches!(scenario.intersection(), BreakEven::PaysOffOnDay { .. }));
+
+        scenario.scale_number_of_day = 1;
+        assert!(matches!(scenario.intersection(), BreakEven::NeverWithinHorizon { .. }));
+
+        // 20 times per hour, 30 minutes each time: the action takes longer than a working day
+        scenario.before_taken_time = 30.0;
+        scenario.before_taken_time_unit = TimeUnit::Minutes;
+        assert!(matches!(scenario.intersection(), BreakEven::PaysOffDuringInvestment { .. }));
+
+        scenario.before_taken_time = 40.0;
+        scenario.before_taken_time_unit = TimeUnit::Seconds;
+        scenario.after_taken_time = 40.0;
+        assert_eq!(scenario.intersection(), BreakEven::NeverAfterNotFaster);
+
+        scenario.repeat_count = 0.0;
+        scenario.after_taken_time = 10.0;
+        assert_eq!(scenario.intersection(), BreakEven::NeverAfterNotFaster);
+
+        // 20 times per hour, 2 hours each time: both processes take the whole day
+        scenario.repeat_count = 20.0;
+        scenario.before_taken_time_unit = TimeUnit::Hours;
+        scenario.before_taken_time = 2.0;
+        scenario.after_taken_time_unit = TimeUnit::Hours;
+        scenario.after_taken_time = 1.0;
+        assert_eq!(scenario.intersection(), BreakEven::CapacitySaturated);
+    }
+
+    #[test]
+    fn maintenance() {
+        // 2 hours per month, 20 working days per month
+        let scenario = Scenario {
+            maintenance_time: 2.0,
+            conf_time_unit: ConfTimeUnit { calendar: Calendar { vacation_days_per_year: DAYS_PER_YEAR / 7.0 * 5.0 - 240.0, ..Calendar::full_time(8.0, 5) }, ..ConfTimeUnit::default() },
+            ..Scenario::default()
+        };
+        assert!((scenario.maintenance_per_day_in_hours() - 0.1).abs() < 1e-9);
+        let without_maintenance = Scenario { maintenance_time: 0.0, ..scenario.clone() };
+        let (day, _) = scenario.intersection().point().unwrap();
+        let (day_without_maintenance, _) = without_maintenance.intersection().point().unwrap();
+        assert!(day > day_without_maintenance);
+        // the after line starts from the investment and grows with the maintenance
+        assert!((scenario.after_at(10.0) - without_maintenance.after_at(10.0) - 0.1 * (10.0 - scenario.invest_days())).abs() < 1e-9);
+        assert!((scenario.evaluate().saved_hours - without_maintenance.evaluate().saved_hours + 0.1 * 90.0).abs() < 1e-9);
+
+        // 2 hours per day of maintenance cost more than what is saved
+        let too_expensive = Scenario { maintenance_period_unit: TimeUnit::Days, ..scenario };
+        assert_eq!(too_expensive.intersection(), BreakEven::NeverAfterNotFaster);
+    }
+
+    #[test]
+    fn sunset() {
+        let forever = Scenario::default();
+        let (day, _) = forever.intersection().point().unwrap();
+        let short_lived = Scenario { lifetime_days: Some(30.0), ..Scenario::default() };
+        assert_eq!(short_lived.intersection().point().unwrap().0, day);
+        // both lines are flat once the process is gone
+        assert_eq!(short_lived.before_at(60.0), short_lived.before_at(30.0));
+        assert_eq!(short_lived.after_at(60.0), short_lived.after_at(30.0));
+        assert!((short_lived.evaluate().saved_hours - forever.evaluate().saved_hours / 3.0).abs() < 1e-9);
+
+        let gone_before_break_even = Scenario { lifetime_days: Some(1.0), ..Scenario::default() };
+        assert_eq!(gone_before_break_even.intersection(), BreakEven::NeverBeforeSunset { day, lifetime_days: 1.0 });
+        let gone_during_investment = Scenario { lifetime_days: Some(0.1), ..Scenario::default() };
+        assert!(matches!(gone_during_investment.intersection(), BreakEven::NeverBeforeSunset { .. }));
+    }
+
+    #[test]
+    fn frequency_growth() {
+        let constant = Scenario::default();
+        let growing = Scenario { frequency_growth: FrequencyGrowth::PercentPerMonth(10.0), ..Scenario::default() };
+        let days_per_month = growing.conf_time_unit.number_of_day_per_month();
+        assert!((growing.executions_per_day_at(days_per_month) - constant.executions_per_day() * 1.1).abs() < 1e-9);
+        assert!(growing.before_at(90.0) > constant.before_at(90.0));
+        // the curves cross where the break-even is
+        let (day, hours) = growing.intersection().point().unwrap();
+        assert!(day < constant.intersection().point().unwrap().0);
+        assert!((growing.improved_at(day) - hours).abs() < 1e-6);
+
+        // twice as often from day 30
+        let schedule = Scenario { frequency_growth: FrequencyGrowth::Schedule(vec![GrowthStep { from_day: 30.0, multiplier: 2.0 }]), ..Scenario::default() };
+        assert!((schedule.before_at(60.0) - constant.before_at(30.0) * 3.0).abs() < 1e-9);
+        assert!((schedule.evaluate().saved_hours - constant.saved_hours_at(30.0) * 3.0 - constant.saved_hours_at(30.0) * 2.0).abs() < 1e-9);
+
+        // a big investment on a process fading out
+        let shrinking = Scenario { frequency_growth: FrequencyGrowth::PercentPerMonth(-50.0), invest_taken_time: 10.0, invest_taken_time_unit: TimeUnit::Days, ..Scenario::default() };
+        assert_eq!(shrinking.intersection(), BreakEven::NeverRecovered);
+    }
+
+    #[test]
+    fn degradation() {
+        let constant = Scenario::default();
+        let degrading = Scenario { before_degradation_percent_per_month: 5.0, ..Scenario::default() };
+        let days_per_month = degrading.conf_time_unit.number_of_day_per_month();
+        assert!((degrading.before_rate_at(days_per_month) - constant.before_rate_at(days_per_month) * 1.05).abs() < 1e-9);
+        assert!(!degrading.has_constant_rates());
+        assert!(degrading.intersection().point().unwrap().0 < constant.intersection().point().unwrap().0);
+        assert!(degrading.evaluate().saved_hours > constant.evaluate().saved_hours);
+
+        // not worth it today, but it will be once the current process gets slow enough
+        let slower_after = Scenario { after_taken_time: 50.0, ..degrading };
+        assert_eq!(Scenario { before_degradation_percent_per_month: 0.0, ..slower_after.clone() }.intersection(), BreakEven::NeverAfterNotFaster);
+        assert!(slower_after.intersection().point().is_some());
+    }
+
+    #[test]
+    fn adoption() {
+        let immediate = Scenario::default();
+        let ramp = Scenario { adoption_ramp: AdoptionRamp::Linear { days: 1.0 }, ..Scenario::default() };
+        // halfway through the ramp, half of the executions use the new process
+        let halfway = ramp.invest_days() + 0.5;
+        assert_eq!(ramp.adoption_at(halfway), 0.5);
+        assert!((ramp.after_rate_at(halfway) - (immediate.before_rate_at(halfway) + immediate.after_rate_at(halfway)) / 2.0).abs() < 1e-9);
+        assert_eq!(ramp.after_rate_at(ramp.invest_days()), immediate.before_rate_at(0.0));
+        // the ramp ends before the break-even, it costs half a day of the full saving
+        let (day, _) = ramp.intersection().point().unwrap();
+        assert!((day - immediate.intersection().point().unwrap().0 - 0.5).abs() < 1e-6);
+
+        let exponential = Scenario { adoption_ramp: AdoptionRamp::Exponential { days: 10.0 }, ..Scenario::default() };
+        assert!(exponential.adoption_at(exponential.invest_days() + 10.0) > 0.95);
+        assert!(exponential.intersection().point().is_some());
+
+        let half = Scenario { adoption_percent: 50.0, ..Scenario::default() };
+        assert!((half.evaluate().saved_hours - immediate.evaluate().saved_hours / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn part_time_investment() {
+        let full_time = Scenario::default();
+        // 4 hours per week over 5 days, 2 hours of investment take 2.5 days instead of a quarter
+        let part_time = Scenario { invest_hours_per_week: Some(4.0), ..Scenario::default() };
+        assert_eq!(part_time.invest_days(), 2.5);
+        assert_eq!(part_time.invest_at(1.25), 1.0);
+        // the current process keeps running meanwhile
+        assert!((part_time.improved_at(1.25) - (1.0 + full_time.before_at(1.25))).abs() < 1e-9);
+        let evaluation = part_time.evaluate();
+        assert!((evaluation.after_series.first().unwrap()[1] - (2.0 + full_time.before_at(2.5))).abs() < 1e-9);
+        // only the investment is left to recover once shipped: 2h / (16/9 - 4/9) h per day
+        let (day, _) = evaluation.break_even.point().unwrap();
+        assert!((day - (2.5 + 1.5)).abs() < 1e-9);
+        // more hours per week than worked is full time
+        let overcommitted = Scenario { invest_hours_per_week: Some(80.0), ..Scenario::default() };
+        assert_eq!(overcommitted.invest_days(), full_time.invest_days());
+    }
+
+    #[test]
+    fn no_worked_day() {
+        // every worked day is a vacation: monthly maintenance never comes around
+        let all_vacation = Scenario {
+            maintenance_time: 2.0,
+            conf_time_unit: ConfTimeUnit { calendar: Calendar { vacation_days_per_year: 300.0, ..Calendar::default() }, ..ConfTimeUnit::default() },
+            ..Scenario::default()
+        };
+        assert_eq!(all_vacation.maintenance_per_day_in_hours(), 0.0);
+        assert!(all_vacation.evaluate().saved_hours.is_finite());
+
+        // no worked hour: the investment never ships
+        let no_worked_hour = Scenario {
+            conf_time_unit: ConfTimeUnit { calendar: Calendar::full_time(0.0, 5), ..ConfTimeUnit::default() },
+            ..Scenario::default()
+        };
+        assert_eq!(no_worked_hour.invest_days(), f64::INFINITY);
+        assert_eq!(no_worked_hour.evaluate().saved_hours, 0.0);
+        assert!(no_worked_hour.intersection().point().is_none());
+    }
+
+    #[test]
+    fn delayed_start() {
+        let now = Scenario::default();
+        let later = Scenario { start_delay_days: 10.0, ..Scenario::default() };
+        assert_eq!(later.ship_day(), 10.25);
+        // the current process runs as is until the start, then is paused during the investment
+        assert_eq!(later.improved_at(5.0), later.before_at(5.0));
+        assert!((later.improved_at(10.125) - (later.before_at(10.0) + 1.0)).abs() < 1e-9);
+        let (now_day, _) = now.intersection().point().unwrap();
+        let (later_day, _) = later.intersection().point().unwrap();
+        assert!((later_day - now_day - 10.0).abs() < 1e-9);
+        // 10 days without the 4/3 h saved per day
+        assert!((later.waiting_cost_at(90.0) - 10.0 * 4.0 / 3.0).abs() < 1e-9);
+        assert!((later.evaluate().saved_hours - 80.0 * 4.0 / 3.0).abs() < 1e-9);
+        assert_eq!(Scenario { start_delay_days: 100.0, ..Scenario::default() }.evaluate().saved_hours, 0.0);
+        // the investment is drawn from its start
+        assert_eq!(later.evaluate().invest_series.first().unwrap(), &[10.0, later.before_at(10.0)]);
+        assert!(Scenario { start_delay_days: 10000.0, ..Scenario::default() }.evaluate().invest_series.len() <= 90);
+        assert!((later.evaluate().after_series.first().unwrap()[1] - (later.before_at(10.0) + 2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn discount() {
+        let undiscounted = Scenario::default();
+        let no_rate = Scenario { discount_rate_percent_per_year: Some(0.0), ..Scenario::default() };
+        let discounted = no_rate.evaluate().discounted.unwrap();
+        // without discounting, the net saving is the saving minus the investment, during which nothing was spent on the process
+        assert!((discounted.net_saved_hours - (undiscounted.before_at(90.0) - undiscounted.improved_at(90.0))).abs() < 1e-9);
+        assert!((discounted.net_saved_hours - discounted.undiscounted_net_saved_hours).abs() < 1e-9);
+        assert!((discounted.break_even_day.unwrap() - 17.0 / 12.0).abs() < 1e-9);
+        assert_eq!(undiscounted.evaluate().discounted, None);
+
+        let scenario = Scenario { discount_rate_percent_per_year: Some(50.0), ..Scenario::default() };
+        let discounted = scenario.evaluate().discounted.unwrap();
+        assert!(discounted.net_saved_hours < no_rate.evaluate().discounted.unwrap().net_saved_hours);
+        assert!(discounted.break_even_day.unwrap() > 17.0 / 12.0);
+        assert_eq!(scenario.discount_factor_at(scenario.conf_time_unit.number_of_day_per_year()), 1.0 / 1.5);
+
+        // not recovered by the end of the projection
+        let short = Scenario { scale_number_of_day: 1, discount_rate_percent_per_year: Some(10.0), ..Scenario::default() };
+        let discounted = short.evaluate().discounted.unwrap();
+        assert!(discounted.undiscounted_net_saved_hours < 0.0);
+        let conf = &short.conf_time_unit;
+        assert_eq!(value_to_human_duration(discounted.undiscounted_net_saved_hours, false, conf),
+                   format!("-{}", value_to_human_duration(-discounted.undiscounted_net_saved_hours, false, conf)));
+        assert_eq!(value_to_human_duration(-2.0, true, conf), format!("-{}", value_to_human_duration(2.0, true, conf)));
+    }
+
+    #[test]
+    fn risk_adjusted() {
+        assert_eq!(Scenario::default().risk_adjusted(), None);
+        let scenario = Scenario { success_probability_percent: 50.0, ..Scenario::default() };
+        let risk_adjusted = scenario.risk_adjusted().unwrap();
+        // half of the time the process still takes 40s: 10/9 h per day expected after, 14/9 h left to recover once shipped
+        assert!((risk_adjusted.after_per_day_in_hours() - 10.0 / 9.0).abs() < 1e-9);
+        let (day, _) = risk_adjusted.intersection().point().unwrap();
+        assert!((day - (0.25 + 14.0 / 6.0)).abs() < 1e-9);
+        // the optimistic figures are unchanged
+        assert_eq!(scenario.intersection(), Scenario::default().intersection());
+
+        let overrun = Scenario { invest_overrun_factor: 2.0, ..Scenario::default() }.risk_adjusted().unwrap();
+        assert_eq!(overrun.invest_time_in_hours(), 4.0);
+        assert_eq!(overrun.invest_days(), 0.5);
+    }
+
+    #[test]
+    fn failures() {
+        // 1% of the manual executions need 2 hours of rework: 72s more per execution
+        let scenario = Scenario { before_failure: Failure { rate_percent: 1.0, ..Failure::default() }, ..Scenario::default() };
+        assert!((scenario.before_execution_hours() - (40.0 + 72.0) / 3600.0).abs() < 1e-12);
+        assert!((scenario.time_taken_per_day_in_hours(1.0, &scenario.before_taken_time_unit, scenario.before_taken_time, &scenario.before_failure).1 - 160.0 * 112.0 / 3600.0).abs() < 1e-9);
+        assert!(scenario.evaluate().saved_hours > Scenario::default().evaluate().saved_hours);
+
+        // not faster, but failing less often is worth it
+        let same_speed = Scenario {
+            after_taken_time: 40.0,
+            after_failure: Failure { rate_percent: 0.1, ..Failure::default() },
+            ..scenario
+        };
+        let (day, _) = same_speed.intersection().point().unwrap();
+        // 160 × 1% × 0.9 × 2h = 2.88 h saved per day
+        assert!((day - (0.25 + (2.0 - 0.25 * same_speed.before_per_day_in_hours()) / 2.88)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn multitasking() {
+        let multitasking = Multitasking { context_switch_time: 0.0, ..Multitasking::default() };
+        let scenario = Scenario { multitasking: Some(multitasking.clone()), ..Scenario::default() };
+        // half of the 40s is waiting, half of it is put to good use
+        assert!((scenario.before_execution_hours() - 30.0 / 3600.0).abs() < 1e-12);
+        assert!((scenario.after_execution_hours() - 7.5 / 3600.0).abs() < 1e-12);
+        assert!((scenario.before_per_day_in_hours() - 160.0 * 30.0 / 3600.0).abs() < 1e-9);
+        assert_eq!(scenario.wall_clock().unwrap(), Scenario::default());
+        assert_eq!(Scenario::default().wall_clock(), None);
+        assert!(scenario.evaluate().saved_hours < Scenario::default().evaluate().saved_hours);
+
+        // switching away costs 2s, never more than the waiting time itself
+        let switching = Multitasking { context_switch_time: 2.0, context_switch_time_unit: TimeUnit::Seconds, ..multitasking.clone() };
+        assert!((switching.effective_hours(40.0 / 3600.0, 50.0, &ConfTimeUnit::default()) - 32.0 / 3600.0).abs() < 1e-12);
+        let useless = Multitasking { efficiency_percent: 0.0, ..switching };
+        assert_eq!(useless.effective_hours(40.0 / 3600.0, 50.0, &ConfTimeUnit::default()), 40.0 / 3600.0);
+        assert_eq!(multitasking.effective_hours(40.0, 0.0, &ConfTimeUnit::default()), 40.0);
+    }
+
+    #[test]
+    fn refocus() {
+        // once a day, 2 minutes of chore and 15 minutes to get back to what was being done
+        let chore = Scenario {
+            repeat_count: 1.0, repeat_count_time_unit: TimeUnit::Days,
+            before_taken_time: 2.0, before_taken_time_unit: TimeUnit::Minutes,
+            after_taken_time: 0.0,
+            ..Scenario::default()
+        };
+        let interrupting = Scenario { refocus: Some(Refocus::default()), ..chore.clone() };
+        assert_eq!(interrupting.before_per_day_in_hours(), 17.0 / 60.0);
+        assert_eq!(interrupting.after_per_day_in_hours(), 0.0);
+        assert!((interrupting.evaluate().saved_hours - 90.0 * 17.0 / 60.0).abs() < 1e-9);
+        assert!(interrupting.intersection().point().unwrap().0 < chore.intersection().point().unwrap().0);
+        // 15 of the 17 minutes saved a day
+        assert!((interrupting.evaluate().refocus_saved_hours.unwrap() - 90.0 * 15.0 / 60.0).abs() < 1e-9);
+        assert_eq!(chore.evaluate().refocus_saved_hours, None);
+
+        // sped up but still interrupting, only the 2 minutes are saved
+        let still_interrupting = Scenario { refocus: Some(Refocus { after: true, ..Refocus::default() }), ..chore.clone() };
+        assert!((still_interrupting.evaluate().saved_hours - chore.evaluate().saved_hours).abs() < 1e-9);
+        assert!(still_interrupting.evaluate().refocus_saved_hours.unwrap().abs() < 1e-9);
+    }
+
+    #[test]
+    fn induced_demand() {
+        let twice = Scenario { induced_demand: InducedDemand::Multiplier(2.0), ..Scenario::default() };
+        // 320 executions of 10s a day once optimized
+        assert!((twice.after_per_day_in_hours() - 8.0 / 9.0).abs() < 1e-9);
+        assert!((twice.evaluate().saved_hours - 90.0 * 8.0 / 9.0).abs() < 1e-9);
+        assert!((twice.extra_executions_at(90.0) - 160.0 * 89.75).abs() < 1e-6);
+        assert_eq!(twice.extra_executions_at(0.1), 0.0);
+        assert_eq!(Scenario::default().extra_executions_at(90.0), 0.0);
+
+        // 40 times per hour instead of 20
+        let target = Scenario { induced_demand: InducedDemand::Target(40.0), ..Scenario::default() };
+        assert_eq!(target.induced_demand_factor(), 2.0);
+        assert_eq!(target.intersection(), twice.intersection());
+
+        // way more often, the improved process ends up taking more time than the current one
+        let too_often = Scenario { induced_demand: InducedDemand::Multiplier(5.0), ..Scenario::default() };
+        assert_eq!(too_often.intersection(), BreakEven::NeverAfterNotFaster);
+        assert!(too_often.extra_executions_at(90.0) > 0.0);
+    }
+
+    #[test]
+    fn money() {
+        let scenario = Scenario {
+            cost_model: CostModel { hourly_rate: 50.0, overhead_multiplier: 1.5, currency: "€".to_string() },
+            ..Scenario::default()
+        };
+        let evaluation = scenario.evaluate();
+        assert_eq!(evaluation.invest_cost, 150.0);
+        assert!((evaluation.saved_money - 9000.0).abs() < 1e-9);
+        assert_eq!(scenario.cost_model.format(evaluation.invest_cost), "€150.00");
+    }
+
+    #[test]
+    fn team() {
+        let single = Scenario::default();
+        let team = Scenario { people_count: 12, ..Scenario::default() };
+        assert_eq!(team.before_at(1.0), single.before_at(1.0) * 12.0);
+        assert_eq!(team.invest_time_in_hours(), 2.0);
+        // the 11 other people keep running the current process during the investment
+        assert_eq!(team.running_share_during_investment(), 11.0 / 12.0);
+        assert!((team.improved_at(0.25) - (2.0 + 11.0 * 16.0 / 9.0 * 0.25)).abs() < 1e-9);
+        assert_eq!(team.improved_series(&[0.125, 0.25]), vec![team.improved_at(0.125), team.improved_at(0.25)]);
+        // 12 × 4/3 h saved per day from day 0.25 recover the 62/9 - 48/9 h left
+        let (single_day, _) = single.intersection().point().unwrap();
+        assert!((single_day - 17.0 / 12.0).abs() < 1e-9);
+        let BreakEven::PaysOffOnDay { day: team_day, .. } = team.intersection() else {
+            panic!("unexpected break-even {:?}", team.intersection());
+        };
+        assert!((team_day - (0.25 + 14.0 / 9.0 / 16.0)).abs() < 1e-9);
+
+        let everyone_invests = Scenario { invest_by_single_person: false, ..team };
+        assert_eq!(everyone_invests.invest_time_in_hours(), 24.0);
+        assert_eq!(everyone_invests.invest_days(), 0.25);
+        assert!((everyone_invests.intersection().point().unwrap().0 - single_day).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fractional_and_every_frequencies() {
+        // once every two weeks
+        let every_two_weeks = Scenario { repeat_count: 2.0, repeat_count_time_unit: TimeUnit::Weeks, repeat_mode: RepeatMode::Every, ..Scenario::default() };
+        assert_eq!(every_two_weeks.executions_per_day(), 0.1);
+        // 4 times a year
+        let four_times_a_year = Scenario { repeat_count: 4.0, repeat_count_time_unit: TimeUnit::Years, ..Scenario::default() };
+        let once_a_quarter = Scenario { repeat_count: 1.0, repeat_count_time_unit: TimeUnit::Quarters, ..Scenario::default() };
+        assert!((four_times_a_year.executions_per_day() - once_a_quarter.executions_per_day()).abs() < 1e-12);
+        // 2.5 times per day
+        let fractional = Scenario { repeat_count: 2.5, repeat_count_time_unit: TimeUnit::Days, ..Scenario::default() };
+        assert_eq!(fractional.executions_per_day(), 2.5);
+        let never = Scenario { repeat_count: 0.0, repeat_mode: RepeatMode::Every, ..Scenario::default() };
+        assert_eq!(never.executions_per_day(), 0.0);
+    }
+
+    #[test]
+    fn custom_units() {
+        let mut scenario = Scenario::default();
+        scenario.conf_time_unit.custom_units = vec![
+            CustomTimeUnit { singular: "sprint".to_string(), plural: "sprints".to_string(), value: 10.0, unit: TimeUnit::Days },
+            CustomTimeUnit { singular: "release".to_string(), plural: "releases".to_string(), value: 6.0, unit: TimeUnit::Weeks },
+        ];
+        let conf = &scenario.conf_time_unit;
+        assert_eq!(TimeUnit::Custom(0).to_hours(2.0, conf), 160.0);
+        assert_eq!(TimeUnit::Custom(0).to_times_per_days(3.0, conf), 0.3);
+        assert_eq!(TimeUnit::Custom(1).to_times_per_days(3.0, conf), 0.1);
+        assert_eq!(TimeUnit::Custom(1).plural(conf), "releases");
+
+        // 3 times per sprint, the investment takes half a sprint
+        scenario.repeat_count = 3.0;
+        scenario.repeat_count_time_unit = TimeUnit::Custom(0);
+        scenario.invest_taken_time = 0.5;
+        scenario.invest_taken_time_unit = TimeUnit::Custom(0);
+        scenario.after_taken_time_unit = TimeUnit::Custom(1);
+        scenario.uncertainty = Some(Uncertainty::around(&scenario, 0.5));
+        let before = scenario.before_at(1.0);
+        scenario.remove_custom_unit(0);
+        assert_eq!(scenario.repeat_count_time_unit, TimeUnit::Days);
+        assert_eq!(scenario.invest_taken_time_unit, TimeUnit::Days);
+        assert_eq!(scenario.invest_taken_time, 5.0);
+        assert_eq!(scenario.after_taken_time_unit, TimeUnit::Custom(0));
+        assert!((scenario.before_at(1.0) - before).abs() < 1e-12);
+        // the ranges follow their input
+        let uncertainty = scenario.uncertainty.unwrap();
+        assert_eq!((uncertainty.invest_taken_time.min, uncertainty.invest_taken_time.max), (2.5, 7.5));
+        assert!((uncertainty.repeat_count.min - 0.15).abs() < 1e-12 && (uncertainty.repeat_count.max - 0.45).abs() < 1e-12);
+        assert_eq!((uncertainty.after_taken_time.min, uncertainty.after_taken_time.max), (5.0, 15.0));
+    }
+}
@@ -0,0 +1,209 @@
+//! Monte Carlo simulation over the ranges of the inputs: how likely and how soon the investment pays off.
+
+use crate::model::{Distribution, Estimate, Scenario, Uncertainty};
+
+/// Always the same seed, so the result does not change from one frame to the other.
+const SEED: u64 = 0x5EED;
+/// Number of days the confidence band is computed on, whatever the projection length.
+const BAND_POINTS: usize = 100;
+const BAND_LOW_PERCENTILE: f64 = 0.1;
+const BAND_HIGH_PERCENTILE: f64 = 0.9;
+
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    pub runs: usize,
+    /// Break-even day of every run having one, even after the projection ends, sorted.
+    pub break_even_days: Vec<f64>,
+    /// P10 and P90 of the before line.
+    pub before_band: (Vec<[f64; 2]>, Vec<[f64; 2]>),
+    /// P10 and P90 of the after line, investment included.
+    pub after_band: (Vec<[f64; 2]>, Vec<[f64; 2]>),
+}
+
+impl Simulation {
+    /// Day by which `percentile` of the runs have paid off, `None` when that many runs never pay off.
+    pub fn break_even_percentile(&self, percentile: f64) -> Option<f64> {
+        let index = ((percentile * self.runs as f64).ceil() as usize).max(1) - 1;
+        self.break_even_days.get(index).copied()
+    }
+
+    /// Share of the runs paying off within the projection.
+    pub fn pays_off_ratio(&self, scale_number_of_day: usize) -> f64 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+        self.break_even_days.iter().filter(|day| **day <= scale_number_of_day as f64).count() as f64 / self.runs as f64
+    }
+
+    /// Number of runs breaking even in each of `bins` intervals between day 0 and `max_day`, as [bin center, count].
+    pub fn histogram(&self, bins: usize, max_day: f64) -> Vec<[f64; 2]> {
+        let width = max_day / bins as f64;
+        let mut counts = vec![0usize; bins];
+        for day in self.break_even_days.iter().filter(|day| **day <= max_day) {
+            counts[((day / width) as usize).min(bins - 1)] += 1;
+        }
+        counts.iter().enumerate().map(|(i, count)| [(i as f64 + 0.5) * width, *count as f64]).collect()
+    }
+}
+
+pub fn simulate(scenario: &Scenario, uncertainty: &Uncertainty) -> Simulation {
+    let mut random = SplitMix64(SEED);
+    let scale = scenario.scale_number_of_day as f64;
+    let band_days: Vec<f64> = (0..BAND_POINTS).map(|i| scale * i as f64 / (BAND_POINTS - 1) as f64).collect();
+    let mut break_even_days = vec![];
+    let mut before_values = vec![vec![]; BAND_POINTS];
+    let mut after_values = vec![vec![]; BAND_POINTS];
+    let mut candidate = scenario.clone();
+    for _ in 0..uncertainty.runs {
+        let distribution = uncertainty.distribution;
+        candidate.before_taken_time = draw(&mut random, distribution, &uncertainty.before_taken_time, scenario.before_taken_time);
+        candidate.after_taken_time = draw(&mut random, distribution, &uncertainty.after_taken_time, scenario.after_taken_time);
+        candidate.invest_taken_time = draw(&mut random, distribution, &uncertainty.invest_taken_time, scenario.invest_taken_time);
+        candidate.repeat_count = draw(&mut random, distribution, &uncertainty.repeat_count, scenario.repeat_count);
+        if let Some((day, _)) = candidate.intersection().point() {
+            break_even_days.push(day);
+        }
+        for (i, (before, after)) in candidate.before_series(&band_days).into_iter().zip(candidate.improved_series(&band_days)).enumerate() {
+            before_values[i].push(before);
+            after_values[i].push(after);
+        }
+    }
+    break_even_days.sort_by(f64::total_cmp);
+    Simulation {
+        runs: uncertainty.runs,
+        break_even_days,
+        before_band: band(&band_days, &mut before_values),
+        after_band: band(&band_days, &mut after_values),
+    }
+}
+
+fn band(days: &[f64], values: &mut [Vec<f64>]) -> (Vec<[f64; 2]>, Vec<[f64; 2]>) {
+    let mut low = Vec::with_capacity(days.len());
+    let mut high = Vec::with_capacity(days.len());
+    for (day, values) in days.iter().zip(values.iter_mut()) {
+        values.sort_by(f64::total_cmp);
+        low.push([*day, percentile(values, BAND_LOW_PERCENTILE)]);
+        high.push([*day, percentile(values, BAND_HIGH_PERCENTILE)]);
+    }
+    (low, high)
+}
+
+fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    sorted[((sorted.len() - 1) as f64 * percentile).round() as usize]
+}
+
+/// Draws a value between the bounds of `estimate`, `likely` being the mode. Bounds are reordered around `likely` if needed.
+fn draw(random: &mut SplitMix64, distribution: Distribution, estimate: &Estimate, likely: f64) -> f64 {
+    let min = estimate.min.min(likely).max(0.0);
+    let max = estimate.max.max(likely);
+    if max - min <= f64::EPSILON {
+        return likely;
+    }
+    match distribution {
+        Distribution::Triangular => {
+            let u = random.next_f64();
+            let mode = (likely - min) / (max - min);
+            if u < mode {
+                min + (u * (max - min) * (likely - min)).sqrt()
+            } else {
+                max - ((1.0 - u) * (max - min) * (max - likely)).sqrt()
+            }
+        }
+        Distribution::Pert => {
+            let alpha = 1.0 + 4.0 * (likely - min) / (max - min);
+            let beta = 1.0 + 4.0 * (max - likely) / (max - min);
+            let x = random.gamma(alpha);
+            let y = random.gamma(beta);
+            min + x / (x + y) * (max - min)
+        }
+    }
+}
+
+/// Small deterministic generator, see https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Box-Muller transform.
+    fn normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    /// Marsaglia and Tsang method, valid for `shape` >= 1 which is always the case of PERT.
+    fn gamma(&mut self, shape: f64) -> f64 {
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let x = self.normal();
+            let v = (1.0 + c * x).powi(3);
+            if v <= 0.0 {
+                continue;
+            }
+            let u = self.next_f64();
+            if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Distribution, Estimate, Scenario, Uncertainty};
+    use crate::monte_carlo::simulate;
+
+    #[test]
+    fn point_estimates_give_the_model_result() {
+        let scenario = Scenario::default();
+        let uncertainty = Uncertainty::around(&scenario, 0.0);
+        let simulation = simulate(&scenario, &uncertainty);
+        let (day, _) = scenario.intersection().point().unwrap();
+        assert_eq!(simulation.break_even_percentile(0.5), Some(day));
+        assert_eq!(simulation.break_even_percentile(0.9), Some(day));
+        assert_eq!(simulation.pays_off_ratio(scenario.scale_number_of_day), 1.0);
+    }
+
+    #[test]
+    fn ranges_spread_the_break_even() {
+        let scenario = Scenario::default();
+        for distribution in [Distribution::Triangular, Distribution::Pert] {
+            let uncertainty = Uncertainty { distribution, ..Uncertainty::around(&scenario, 0.5) };
+            let simulation = simulate(&scenario, &uncertainty);
+            let p50 = simulation.break_even_percentile(0.5).unwrap();
+            let p90 = simulation.break_even_percentile(0.9).unwrap();
+            assert!(p50 < p90);
+            // symmetric ranges, the median stays close to the point estimate
+            assert!((p50 - scenario.intersection().point().unwrap().0).abs() < 0.5);
+            let (low, high) = &simulation.after_band;
+            assert!(low.iter().zip(high.iter()).all(|(low, high)| low[1] <= high[1]));
+            assert_eq!(simulation.histogram(10, 90.0).iter().map(|bin| bin[1]).sum::<f64>(), simulation.runs as f64);
+        }
+    }
+
+    #[test]
+    fn runs_never_paying_off() {
+        // the optimised process may be slower than the current one
+        let scenario = Scenario::default();
+        let uncertainty = Uncertainty { after_taken_time: Estimate { min: 10.0, max: 80.0 }, ..Uncertainty::around(&scenario, 0.0) };
+        let simulation = simulate(&scenario, &uncertainty);
+        assert!(simulation.pays_off_ratio(scenario.scale_number_of_day) < 1.0);
+        assert_eq!(simulation.break_even_percentile(0.99), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Sensitivity analysis: how much the break-even and the saved time move when a single input is off by ±X%.
+
+use crate::model::{AdoptionRamp, FrequencyGrowth, InducedDemand, Scenario};
+
+/// An input of the scenario and how to scale it by a factor.
+struct Input {
+    name: &'static str,
+    scale: fn(&mut Scenario, f64),
+}
+
+/// Every numeric input of the process, the investment and the calendar. Inputs left unset (no lifetime, no refocusing…)
+/// do not move anything. Left out on purpose: the cost model, which only turns hours into money, the projection length,
+/// which is the frame the outcome is read in, and the failure recovery and multitasking details, which only refine the
+/// time per execution already perturbed as a whole.
+const INPUTS: [Input; 22] = [
+    Input { name: "Repeat count", scale: |scenario, factor| scenario.repeat_count *= factor },
+    Input { name: "Time before", scale: |scenario, factor| scenario.before_taken_time *= factor },
+    Input { name: "Degradation before", scale: |scenario, factor| scenario.before_degradation_percent_per_month *= factor },
+    Input { name: "Failure rate before", scale: |scenario, factor| scenario.before_failure.rate_percent = (scenario.before_failure.rate_percent * factor).min(100.0) },
+    Input {
+        name: "Frequency growth",
+        scale: |scenario, factor| match &mut scenario.frequency_growth {
+            FrequencyGrowth::Constant => {}
+            FrequencyGrowth::PercentPerMonth(percent) => *percent *= factor,
+            FrequencyGrowth::Schedule(steps) => steps.iter_mut().for_each(|step| step.multiplier *= factor),
+        },
+    },
+    Input { name: "Refocus time", scale: |scenario, factor| if let Some(refocus) = scenario.refocus.as_mut() { refocus.time *= factor } },
+    Input { name: "Time after", scale: |scenario, factor| scenario.after_taken_time *= factor },
+    Input { name: "Failure rate after", scale: |scenario, factor| scenario.after_failure.rate_percent = (scenario.after_failure.rate_percent * factor).min(100.0) },
+    Input { name: "Adoption", scale: |scenario, factor| scenario.adoption_percent = (scenario.adoption_percent * factor).min(100.0) },
+    Input {
+        name: "Adoption ramp",
+        scale: |scenario, factor| match &mut scenario.adoption_ramp {
+            AdoptionRamp::Immediate => {}
+            AdoptionRamp::Linear { days } | AdoptionRamp::Exponential { days } => *days *= factor,
+        },
+    },
+    Input {
+        name: "Induced demand",
+        scale: |scenario, factor| match &mut scenario.induced_demand {
+            InducedDemand::Unchanged => {}
+            InducedDemand::Multiplier(value) | InducedDemand::Target(value) => *value *= factor,
+        },
+    },
+    Input { name: "Investment", scale: |scenario, factor| scenario.invest_taken_time *= factor },
+    Input { name: "Part-time investment", scale: |scenario, factor| if let Some(hours) = scenario.invest_hours_per_week.as_mut() { *hours *= factor } },
+    Input { name: "Start delay", scale: |scenario, factor| scenario.start_delay_days *= factor },
+    Input { name: "Success probability", scale: |scenario, factor| scenario.success_probability_percent = (scenario.success_probability_percent * factor).min(100.0) },
+    Input { name: "Investment overrun", scale: |scenario, factor| scenario.invest_overrun_factor = (scenario.invest_overrun_factor * factor).max(1.0) },
+    Input { name: "Maintenance", scale: |scenario, factor| scenario.maintenance_time *= factor },
+    Input { name: "Lifetime", scale: |scenario, factor| if let Some(days) = scenario.lifetime_days.as_mut() { *days *= factor } },
+    Input { name: "Discount rate", scale: |scenario, factor| if let Some(percent) = scenario.discount_rate_percent_per_year.as_mut() { *percent *= factor } },
+    Input { name: "People", scale: |scenario, factor| scenario.people_count = ((scenario.people_count as f64 * factor).round() as usize).max(1) },
+    Input {
+        name: "Worked hours per day",
+        scale: |scenario, factor| scenario.conf_time_unit.calendar.hours_per_weekday.iter_mut().for_each(|hours| *hours = (*hours * factor).min(24.0)),
+    },
+    Input { name: "Vacation days", scale: |scenario, factor| scenario.conf_time_unit.calendar.vacation_days_per_year *= factor },
+];
+
+/// Outcome of the scenario for one value of the inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    /// `None` when it never pays off.
+    pub break_even_day: Option<f64>,
+    pub saved_hours: f64,
+}
+
+impl Outcome {
+    /// Only the two headline numbers, cheaper than [`Scenario::evaluate`].
+    fn of(scenario: &Scenario) -> Self {
+        Self {
+            break_even_day: scenario.intersection().point().map(|(day, _)| day),
+            saved_hours: scenario.saved_hours_at(scenario.scale_number_of_day as f64),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Sensitivity {
+    pub input: &'static str,
+    /// With the input decreased by the variation.
+    pub low: Outcome,
+    /// With the input increased by the variation.
+    pub high: Outcome,
+}
+
+impl Sensitivity {
+    /// How far apart the break-even days are, infinite when one side never pays off and the other does.
+    pub fn break_even_swing(&self) -> f64 {
+        match (self.low.break_even_day, self.high.break_even_day) {
+            (Some(low), Some(high)) => (high - low).abs(),
+            (None, None) => 0.0,
+            _ => f64::INFINITY,
+        }
+    }
+
+    pub fn saved_hours_swing(&self) -> f64 {
+        (self.high.saved_hours - self.low.saved_hours).abs()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Tornado {
+    pub base: Outcome,
+    /// One per input, in no particular order.
+    pub sensitivities: Vec<Sensitivity>,
+}
+
+impl Tornado {
+    /// Sorted from the least to the most influential input, the way a tornado chart is drawn from the bottom.
+    pub fn ranked_by(&self, swing: fn(&Sensitivity) -> f64) -> Vec<&Sensitivity> {
+        let mut ranked: Vec<&Sensitivity> = self.sensitivities.iter().collect();
+        ranked.sort_by(|a, b| swing(a).total_cmp(&swing(b)));
+        ranked
+    }
+}
+
+/// Perturbs each input by ±`variation` (0.2 for ±20%), one at a time.
+pub fn tornado(scenario: &Scenario, variation: f64) -> Tornado {
+    let sensitivities = INPUTS.iter().map(|input| {
+        let outcome = |factor: f64| {
+            let mut candidate = scenario.clone();
+            (input.scale)(&mut candidate, factor);
+            Outcome::of(&candidate)
+        };
+        Sensitivity { input: input.name, low: outcome(1.0 - variation), high: outcome(1.0 + variation) }
+    }).collect();
+    Tornado { base: Outcome::of(scenario), sensitivities }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::Scenario;
+    use crate::sensitivity::{tornado, Sensitivity};
+
+    #[test]
+    fn default_scenario_tornado() {
+        let tornado = tornado(&Scenario::default(), 0.2);
+        let investment = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Investment").unwrap();
+        // a bigger investment takes longer to recover, but does not change the daily saving
+        assert!(investment.low.break_even_day < investment.high.break_even_day);
+        assert_eq!(investment.saved_hours_swing(), 0.0);
+        // no vacation day by default, nothing to perturb
+        let vacations = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Vacation days").unwrap();
+        assert_eq!(vacations.break_even_swing(), 0.0);
+
+        let ranked = tornado.ranked_by(Sensitivity::saved_hours_swing);
+        assert!(ranked.windows(2).all(|pair| pair[0].saved_hours_swing() <= pair[1].saved_hours_swing()));
+        assert_eq!(ranked.len(), tornado.sensitivities.len());
+    }
+
+    #[test]
+    fn later_inputs_are_perturbed() {
+        let scenario = Scenario { start_delay_days: 10.0, lifetime_days: Some(60.0), ..Scenario::default() };
+        let tornado = tornado(&scenario, 0.2);
+        let start_delay = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Start delay").unwrap();
+        assert!((start_delay.high.break_even_day.unwrap() - start_delay.low.break_even_day.unwrap() - 4.0).abs() < 1e-9);
+        let lifetime = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Lifetime").unwrap();
+        assert!(lifetime.saved_hours_swing() > 0.0);
+        // no refocusing to perturb
+        let refocus = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Refocus time").unwrap();
+        assert_eq!(refocus.saved_hours_swing(), 0.0);
+    }
+
+    #[test]
+    fn never_paying_off_ranks_first() {
+        // 10% slower after would make it not worth it
+        let scenario = Scenario { after_taken_time: 38.0, ..Scenario::default() };
+        let tornado = tornado(&scenario, 0.1);
+        let ranked = tornado.ranked_by(Sensitivity::break_even_swing);
+        assert_eq!(ranked.last().unwrap().break_even_swing(), f64::INFINITY);
+        let after = tornado.sensitivities.iter().find(|sensitivity| sensitivity.input == "Time after").unwrap();
+        assert_eq!(after.high.break_even_day, None);
+        assert!(ranked.iter().rev().take(2).any(|sensitivity| sensitivity.input == "Time after"));
+    }
+}